@@ -1,55 +1,123 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Item};
+use syn::{Item, ItemMod, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
 
 #[derive(Debug)]
 pub struct AssetAPIFields {
-    pub transaction_payment: Ident,
-    pub balance: Ident,
-    pub call: Ident,
+    pub transaction_payment: Type,
+    pub balance: Type,
+    pub call: Type,
+    pub foreign_assets: Option<ForeignAssetsAPIFields>,
 }
 
-impl TryFrom<&[Item]> for AssetAPIFields {
-    type Error = &'static str;
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
+/// Types of `pallet_assets` and `pallet_asset_manager`, declared only by runtimes that have both pallets.
+#[derive(Debug)]
+pub struct ForeignAssetsAPIFields {
+    pub assets: Type,
+    pub asset_manager: Type,
+    pub asset_id: Type,
+    pub asset_type: Type,
+    pub account_id: Type,
+}
+
+impl TryFrom<&ItemMod> for AssetAPIFields {
+    type Error = syn::Error;
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
         let mut transaction_payment = None;
         let mut call = None;
         let mut balance = None;
 
-        for item in value {
+        for item in mod_items(value)? {
             if let Item::Type(ty) = item {
                 if ty.ident == "TransactionPayment" {
-                    transaction_payment = Some(fetch_ident(&ty.ty))
+                    transaction_payment = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "RuntimeCall" {
-                    call = Some(fetch_ident(&ty.ty))
+                    call = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Balance" {
-                    balance = Some(fetch_ident(&ty.ty))
+                    balance = errors.collect(fetch_type(&ty.ty))
                 }
             }
         }
 
         let transaction_payment =
-            transaction_payment.ok_or("`type TransactionPayment` not specified, but required")?;
-        let balance = balance.ok_or("`type Balance` not specified, but required")?;
-        let call = call.ok_or("`type RuntimeCall` not specified, but required")?;
+            errors.require(transaction_payment, &value.ident, "TransactionPayment");
+        let balance = errors.require(balance, &value.ident, "Balance");
+        let call = errors.require(call, &value.ident, "RuntimeCall");
+        let foreign_assets = errors.collect(ForeignAssetsAPIFields::parse(value));
+        let checked = errors.finish()?;
         Ok(AssetAPIFields {
-            transaction_payment,
-            balance,
-            call,
+            transaction_payment: transaction_payment.get(&checked)?,
+            balance: balance.get(&checked)?,
+            call: call.get(&checked)?,
+            foreign_assets: foreign_assets.flatten(),
         })
     }
 }
 
+impl ForeignAssetsAPIFields {
+    /// Returns the types if any of them is declared, the others are required then.
+    fn parse(value: &ItemMod) -> syn::Result<Option<Self>> {
+        let mut errors = Errors::default();
+        let mut assets = None;
+        let mut asset_manager = None;
+        let mut asset_id = None;
+        let mut asset_type = None;
+        let mut account_id = None;
+
+        for item in mod_items(value)? {
+            if let Item::Type(ty) = item {
+                if ty.ident == "Assets" {
+                    assets = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetManager" {
+                    asset_manager = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetId" {
+                    asset_id = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetType" {
+                    asset_type = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AccountId" {
+                    account_id = errors.collect(fetch_type(&ty.ty))
+                }
+            }
+        }
+        if assets.is_none()
+            && asset_manager.is_none()
+            && asset_id.is_none()
+            && asset_type.is_none()
+            && account_id.is_none()
+        {
+            errors.finish()?;
+            return Ok(None);
+        }
+
+        let assets = errors.require(assets, &value.ident, "Assets");
+        let asset_manager = errors.require(asset_manager, &value.ident, "AssetManager");
+        let asset_id = errors.require(asset_id, &value.ident, "AssetId");
+        let asset_type = errors.require(asset_type, &value.ident, "AssetType");
+        let account_id = errors.require(account_id, &value.ident, "AccountId");
+        let checked = errors.finish()?;
+        Ok(Some(ForeignAssetsAPIFields {
+            assets: assets.get(&checked)?,
+            asset_manager: asset_manager.get(&checked)?,
+            asset_id: asset_id.get(&checked)?,
+            asset_type: asset_type.get(&checked)?,
+            account_id: account_id.get(&checked)?,
+        }))
+    }
+}
+
 pub fn assets_apis(
-    runtime: &Ident,
-    block: &Ident,
-    transaction_payment: &Ident,
-    balance: &Ident,
-    call: &Ident,
+    runtime: &Type,
+    block: &Type,
+    transaction_payment: &Type,
+    balance: &Type,
+    call: &Type,
+    foreign_assets: Option<&ForeignAssetsAPIFields>,
 ) -> TokenStream {
-    quote! {
+    let mut res = quote! {
         impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<#block, #balance>
             for #runtime
         {
@@ -57,19 +125,19 @@ pub fn assets_apis(
                 uxt: <#block as sp_runtime::traits::Block>::Extrinsic,
                 len: u32,
             ) -> pallet_transaction_payment_rpc_runtime_api::RuntimeDispatchInfo<#balance> {
-                #transaction_payment::query_info(uxt, len)
+                <#transaction_payment>::query_info(uxt, len)
             }
             fn query_fee_details(
                 uxt: <#block as sp_runtime::traits::Block>::Extrinsic,
                 len: u32,
             ) -> pallet_transaction_payment::FeeDetails<#balance> {
-                #transaction_payment::query_fee_details(uxt, len)
+                <#transaction_payment>::query_fee_details(uxt, len)
             }
             fn query_weight_to_fee(weight: frame_support::weights::Weight) -> #balance {
-                #transaction_payment::weight_to_fee(weight)
+                <#transaction_payment>::weight_to_fee(weight)
             }
             fn query_length_to_fee(length: u32) -> #balance {
-                #transaction_payment::length_to_fee(length)
+                <#transaction_payment>::length_to_fee(length)
             }
         }
 
@@ -84,20 +152,50 @@ pub fn assets_apis(
                 call: #call,
                 len: u32,
             ) -> pallet_transaction_payment::RuntimeDispatchInfo<#balance> {
-                #transaction_payment::query_call_info(call, len)
+                <#transaction_payment>::query_call_info(call, len)
             }
             fn query_call_fee_details(
                 call: #call,
                 len: u32,
             ) -> pallet_transaction_payment::FeeDetails<#balance> {
-                #transaction_payment::query_call_fee_details(call, len)
+                <#transaction_payment>::query_call_fee_details(call, len)
             }
             fn query_weight_to_fee(weight: frame_support::weights::Weight) -> #balance {
-                #transaction_payment::weight_to_fee(weight)
+                <#transaction_payment>::weight_to_fee(weight)
             }
             fn query_length_to_fee(length: u32) -> #balance {
-                #transaction_payment::length_to_fee(length)
+                <#transaction_payment>::length_to_fee(length)
             }
         }
+    };
+    if let Some(ForeignAssetsAPIFields {
+        assets,
+        asset_manager,
+        asset_id,
+        asset_type,
+        account_id,
+    }) = foreign_assets
+    {
+        res.extend(quote! {
+            impl pallet_assets::AssetsApi<#block, #account_id, #balance, #asset_id> for #runtime {
+                fn account_balances(account: #account_id) -> sp_std::prelude::Vec<(#asset_id, #balance)> {
+                    <#assets>::account_balances(account)
+                }
+            }
+
+            impl openzeppelin_pallet_abstractions::runtime_api::ForeignAssetsApi<#block, #asset_type, #asset_id>
+                for #runtime
+            {
+                fn asset_id(asset_type: #asset_type) -> Option<#asset_id> {
+                    <#asset_manager as xcm_primitives::AssetTypeGetter<#asset_id, #asset_type>>::get_asset_id(asset_type)
+                }
+
+                fn asset_type(asset_id: #asset_id) -> Option<#asset_type> {
+                    <#asset_manager as xcm_primitives::AssetTypeGetter<#asset_id, #asset_type>>::get_asset_type(asset_id)
+                }
+            }
+        });
     }
+
+    res
 }
@@ -1,8 +1,13 @@
 use proc_macro2::Ident;
 use quote::quote;
-use syn::Item;
+use syn::{Attribute, Item, ItemMod, ItemStruct, ItemType, ItemUse, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::{
+    construct_runtime::{check_exclusions, parse_exclusions},
+    errors::Errors,
+    models::ConstructAbstractions,
+};
 
 #[derive(Default)]
 pub struct AbstractionState {
@@ -11,161 +16,357 @@ pub struct AbstractionState {
 }
 
 pub struct BenchmarkAPIFields {
-    pub all_pallets_with_system: Ident,
-    pub parachain_system: Ident,
-    pub system: Ident,
+    pub all_pallets_with_system: Type,
+    pub parachain_system: Type,
+    pub system: Type,
+    /// `use` items of `mod benchmarks`, imported by the benchmarking functions instead of the
+    /// modules of the runtime templates
+    pub imports: Vec<ItemUse>,
+    /// Pallets of the abstractions declared as structs and of the `#[pallet]` types of `mod benchmarks`
+    pub pallets: Vec<BenchmarkedPallet>,
     pub xcm_fields: Option<XCMBenchmarkAPIFields>,
 }
 
-impl TryFrom<&[Item]> for BenchmarkAPIFields {
-    type Error = &'static str;
+/// Pallet passed to `list_benchmark!` and `add_benchmark!`.
+pub struct BenchmarkedPallet {
+    /// `cfg` attributes of the abstraction or of the pallet type
+    pub cfgs: Vec<Attribute>,
+    pub name: Ident,
+    pub module: proc_macro2::TokenStream,
+}
+
+impl BenchmarkedPallet {
+    /// Lists the benchmarked pallets of the abstraction, except the excluded ones.
+    fn from_abstraction(item: &ItemStruct) -> syn::Result<Vec<Self>> {
+        let abstraction = ConstructAbstractions::try_from(item)?;
+        let excluded = parse_exclusions(&item.attrs)?;
+        let mut errors = Errors::default();
+        check_exclusions(&mut errors, item, &excluded, abstraction.pallets());
+        errors.finish()?;
+
+        let benchmarked = abstraction.benchmarked_pallets();
+        Ok(abstraction
+            .pallets()
+            .iter()
+            .filter(|(name, _)| {
+                benchmarked.contains(name) && !excluded.iter().any(|excluded| excluded == name)
+            })
+            .map(|(name, module)| {
+                let name = Ident::new(name, item.ident.span());
+                let module = Ident::new(module, item.ident.span());
+                BenchmarkedPallet {
+                    cfgs: cfgs(&item.attrs),
+                    name,
+                    module: quote! { #module },
+                }
+            })
+            .collect())
+    }
+
+    fn from_pallet(item: &ItemType) -> Self {
+        let ty = &item.ty;
+        BenchmarkedPallet {
+            cfgs: cfgs(&item.attrs),
+            name: item.ident.clone(),
+            module: quote! { #ty },
+        }
+    }
 
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
+    /// Type implementing the benchmarks, a helper pallet for the pallets benchmarked through one.
+    fn location(&self, runtime: &Type) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        if name == "System" {
+            quote! { SystemBench::<#runtime> }
+        } else if name == "Session" {
+            quote! { SessionBench::<#runtime> }
+        } else if name == "PolkadotXcm" {
+            quote! { PalletXcmExtrinsicsBenchmark::<#runtime> }
+        } else {
+            quote! { #name }
+        }
+    }
+}
+
+fn cfgs(attrs: &[Attribute]) -> Vec<Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .cloned()
+        .collect()
+}
+
+fn is_pallet(item: &ItemType) -> bool {
+    item.attrs.iter().any(|attr| attr.path().is_ident("pallet"))
+}
+
+impl TryFrom<&ItemMod> for BenchmarkAPIFields {
+    type Error = syn::Error;
+
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
         let mut all_pallets_with_system = None;
         let mut parachain_system = None;
         let mut system = None;
-
-        for item in value {
-            if let Item::Type(ty) = item {
-                if ty.ident == "AllPalletsWithSystem" {
-                    all_pallets_with_system = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "ParachainSystem" {
-                    parachain_system = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "System" {
-                    system = Some(fetch_ident(&ty.ty))
+        let mut imports = vec![];
+        let mut pallets = vec![];
+
+        for item in mod_items(value)? {
+            match item {
+                Item::Type(ty) if is_pallet(ty) => pallets.push(BenchmarkedPallet::from_pallet(ty)),
+                Item::Struct(item) => {
+                    if let Some(abstraction) =
+                        errors.collect(BenchmarkedPallet::from_abstraction(item))
+                    {
+                        pallets.extend(abstraction);
+                    }
                 }
+                Item::Type(ty) => {
+                    if ty.ident == "AllPalletsWithSystem" {
+                        all_pallets_with_system = errors.collect(fetch_type(&ty.ty))
+                    } else if ty.ident == "ParachainSystem" {
+                        parachain_system = errors.collect(fetch_type(&ty.ty))
+                    } else if ty.ident == "System" {
+                        system = errors.collect(fetch_type(&ty.ty))
+                    }
+                }
+                Item::Use(import) => imports.push(import.clone()),
+                _ => (),
             }
         }
 
-        let all_pallets_with_system = all_pallets_with_system
-            .ok_or("type `AllPalletsWithSystem` not specified, but required")?;
-        let parachain_system =
-            parachain_system.ok_or("type `ParachainSystem` not specified, but required")?;
-        let system = system.ok_or("type `System` not specified, but required")?;
-        let xcm_fields = XCMBenchmarkAPIFields::try_from(value)
-            .map_err(|e| println!("{e:?}"))
-            .ok();
+        let all_pallets_with_system = errors.require(
+            all_pallets_with_system,
+            &value.ident,
+            "AllPalletsWithSystem",
+        );
+        let parachain_system = errors.require(parachain_system, &value.ident, "ParachainSystem");
+        let system = errors.require(system, &value.ident, "System");
+        // Foreign assets are transferred by the XCM benchmarks of runtimes benchmarking `pallet_asset_manager`
+        let foreign_assets = pallets.iter().any(|pallet| pallet.name == "AssetManager");
+        let xcm_fields = errors.require_ok(XCMBenchmarkAPIFields::parse(value, foreign_assets));
+        let checked = errors.finish()?;
 
         Ok(BenchmarkAPIFields {
-            all_pallets_with_system,
-            parachain_system,
-            system,
-            xcm_fields,
+            all_pallets_with_system: all_pallets_with_system.get(&checked)?,
+            parachain_system: parachain_system.get(&checked)?,
+            system: system.get(&checked)?,
+            imports,
+            pallets,
+            xcm_fields: xcm_fields.get(&checked)?,
         })
     }
 }
 
+/// Types of the XCM benchmarking setup, declaring any of them enables it.
+const XCM_TYPES: [&str; 8] = [
+    "RelayLocation",
+    "ExistentialDeposit",
+    "XCMConfig",
+    "Cents",
+    "FeeAssetId",
+    "TransactionByteFee",
+    "Balances",
+    "NativeLocation",
+];
+
+/// Types specific to the foreign assets setup, declaring any of them enables XCM benchmarking with foreign assets.
+/// The other types of the setup, like `AccountId`, are common to runtimes without foreign assets.
+const ASSET_TYPES: [&str; 3] = ["Assets", "AssetManager", "AssetType"];
+
+/// Whether the module declares any of the types, except `#[pallet]` types.
+fn declares_any(value: &ItemMod, names: &[&str]) -> syn::Result<bool> {
+    Ok(mod_items(value)?.iter().any(|item| {
+        matches!(item, Item::Type(ty) if !is_pallet(ty) && names.iter().any(|name| ty.ident == name))
+    }))
+}
+
+/// Whether the XCM benchmarks of the module transfer foreign assets, benchmarking the `Assets`
+/// abstraction or declaring any of the foreign assets types.
+pub fn transfers_foreign_assets(value: &ItemMod) -> syn::Result<bool> {
+    let benchmarks_assets = mod_items(value)?
+        .iter()
+        .any(|item| matches!(item, Item::Struct(item) if item.ident == "Assets"));
+    Ok(benchmarks_assets || declares_any(value, &ASSET_TYPES)?)
+}
+
 pub struct XCMBenchmarkAPIFields {
-    pub assets: Ident,
-    pub asset_manager: Ident,
-    pub asset_type: Ident,
-    pub runtime_origin: Ident,
-    pub relay_location: Ident,
-    pub existential_deposit: Ident,
-    pub asset_id: Ident,
-    pub xcm_config: Ident,
-    pub account_id: Ident,
-    pub cents: Ident,
-    pub fee_asset_id: Ident,
-    pub transaction_byte_fee: Ident,
-    pub address: Ident,
-    pub balances: Ident,
+    pub relay_location: Type,
+    pub existential_deposit: Type,
+    pub xcm_config: Type,
+    pub cents: Type,
+    pub fee_asset_id: Type,
+    pub transaction_byte_fee: Type,
+    pub balances: Type,
+    pub transfers: XCMBenchmarkTransfers,
 }
 
-impl TryFrom<&[Item]> for XCMBenchmarkAPIFields {
-    type Error = &'static str;
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
-        let mut assets = None;
-        let mut asset_manager = None;
-        let mut asset_type = None;
-        let mut runtime_origin = None;
+/// Assets transferred by the `pallet_xcm` benchmarks.
+#[allow(clippy::large_enum_variant)]
+pub enum XCMBenchmarkTransfers {
+    /// Foreign assets registered in `pallet_asset_manager`
+    ForeignAssets(AssetsBenchmarkAPIFields),
+    /// The native currency, reserved in the parachain
+    Native { native_location: Type },
+}
+
+impl XCMBenchmarkAPIFields {
+    /// Returns the types if any of them is declared, the others are required then.
+    fn parse(value: &ItemMod, foreign_assets: bool) -> syn::Result<Option<Self>> {
+        if !declares_any(value, &XCM_TYPES)? && !declares_any(value, &ASSET_TYPES)? {
+            return Ok(None);
+        }
+        let mut errors = Errors::default();
         let mut relay_location = None;
         let mut existential_deposit = None;
-        let mut asset_id = None;
         let mut xcm_config = None;
-        let mut account_id = None;
         let mut cents = None;
         let mut fee_asset_id = None;
         let mut transaction_byte_fee = None;
-        let mut address = None;
         let mut balances = None;
 
-        for item in value {
+        for item in mod_items(value)? {
             if let Item::Type(ty) = item {
-                if ty.ident == "Assets" {
-                    assets = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "AssetManager" {
-                    asset_manager = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "AssetType" {
-                    asset_type = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "RuntimeOrigin" {
-                    runtime_origin = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "RelayLocation" {
-                    relay_location = Some(fetch_ident(&ty.ty))
+                if is_pallet(ty) {
+                    continue;
+                }
+                if ty.ident == "RelayLocation" {
+                    relay_location = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "ExistentialDeposit" {
-                    existential_deposit = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "AssetId" {
-                    asset_id = Some(fetch_ident(&ty.ty))
+                    existential_deposit = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "XCMConfig" {
-                    xcm_config = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "AccountId" {
-                    account_id = Some(fetch_ident(&ty.ty))
+                    xcm_config = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Cents" {
-                    cents = Some(fetch_ident(&ty.ty))
+                    cents = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "FeeAssetId" {
-                    fee_asset_id = Some(fetch_ident(&ty.ty))
+                    fee_asset_id = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "TransactionByteFee" {
-                    transaction_byte_fee = Some(fetch_ident(&ty.ty))
-                } else if ty.ident == "Address" {
-                    address = Some(fetch_ident(&ty.ty))
+                    transaction_byte_fee = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Balances" {
-                    balances = Some(fetch_ident(&ty.ty))
+                    balances = errors.collect(fetch_type(&ty.ty))
                 }
             }
         }
 
-        let assets = assets.ok_or("type `Assets` not specified, but required")?;
-        let asset_manager =
-            asset_manager.ok_or("type `AssetManager` not specified, but required")?;
-        let asset_type = asset_type.ok_or("type `AssetType` not specified, but required")?;
-        let runtime_origin =
-            runtime_origin.ok_or("type `RuntimeOrigin` not specified, but required")?;
-        let relay_location =
-            relay_location.ok_or("type `RelayLocation` not specified, but required")?;
+        let relay_location = errors.require(relay_location, &value.ident, "RelayLocation");
         let existential_deposit =
-            existential_deposit.ok_or("type `ExistentialDeposit` not specified, but required")?;
-        let asset_id = asset_id.ok_or("type `AssetId` not specified, but required")?;
-        let xcm_config = xcm_config.ok_or("type `XCMConfig` not specified, but required")?;
-        let account_id = account_id.ok_or("type `AccountId` not specified, but required")?;
-        let cents = cents.ok_or("type `Cents` not specified, but required")?;
-        let fee_asset_id = fee_asset_id.ok_or("type `FeeAssetId` not specified, but required")?;
+            errors.require(existential_deposit, &value.ident, "ExistentialDeposit");
+        let xcm_config = errors.require(xcm_config, &value.ident, "XCMConfig");
+        let cents = errors.require(cents, &value.ident, "Cents");
+        let fee_asset_id = errors.require(fee_asset_id, &value.ident, "FeeAssetId");
         let transaction_byte_fee =
-            transaction_byte_fee.ok_or("type `TransactionByteFee` not specified, but required")?;
-        let address = address.ok_or("type `Address` not specified, but required")?;
-        let balances = balances.ok_or("type `Balances` not specified, but required")?;
-
-        Ok(XCMBenchmarkAPIFields {
-            assets,
-            asset_manager,
-            asset_type,
-            runtime_origin,
-            relay_location,
-            existential_deposit,
-            asset_id,
-            xcm_config,
-            account_id,
-            cents,
-            fee_asset_id,
-            transaction_byte_fee,
-            address,
-            balances,
+            errors.require(transaction_byte_fee, &value.ident, "TransactionByteFee");
+        let balances = errors.require(balances, &value.ident, "Balances");
+        let transfers = errors.require_ok(XCMBenchmarkTransfers::parse(value, foreign_assets));
+        let checked = errors.finish()?;
+        Ok(Some(XCMBenchmarkAPIFields {
+            relay_location: relay_location.get(&checked)?,
+            existential_deposit: existential_deposit.get(&checked)?,
+            xcm_config: xcm_config.get(&checked)?,
+            cents: cents.get(&checked)?,
+            fee_asset_id: fee_asset_id.get(&checked)?,
+            transaction_byte_fee: transaction_byte_fee.get(&checked)?,
+            balances: balances.get(&checked)?,
+            transfers: transfers.get(&checked)?,
+        }))
+    }
+}
+
+impl XCMBenchmarkTransfers {
+    /// Transfers foreign assets if the runtime benchmarks `pallet_asset_manager` or any of their types is declared,
+    /// and the native currency otherwise.
+    fn parse(value: &ItemMod, foreign_assets: bool) -> syn::Result<Self> {
+        if foreign_assets || declares_any(value, &ASSET_TYPES)? {
+            return AssetsBenchmarkAPIFields::try_from(value).map(Self::ForeignAssets);
+        }
+        let mut errors = Errors::default();
+        let mut native_location = None;
+
+        for item in mod_items(value)? {
+            if let Item::Type(ty) = item {
+                if !is_pallet(ty) && ty.ident == "NativeLocation" {
+                    native_location = errors.collect(fetch_type(&ty.ty))
+                }
+            }
+        }
+
+        let native_location = errors.require(native_location, &value.ident, "NativeLocation");
+        let checked = errors.finish()?;
+        Ok(Self::Native {
+            native_location: native_location.get(&checked)?,
+        })
+    }
+}
+
+/// Types of the XCM benchmarking setup with foreign assets registered in `pallet_asset_manager`.
+pub struct AssetsBenchmarkAPIFields {
+    pub assets: Type,
+    pub asset_manager: Type,
+    pub asset_type: Type,
+    pub runtime_origin: Type,
+    pub asset_id: Type,
+    pub account_id: Type,
+    pub address: Type,
+}
+
+impl TryFrom<&ItemMod> for AssetsBenchmarkAPIFields {
+    type Error = syn::Error;
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
+        let mut assets = None;
+        let mut asset_manager = None;
+        let mut asset_type = None;
+        let mut runtime_origin = None;
+        let mut asset_id = None;
+        let mut account_id = None;
+        let mut address = None;
+
+        for item in mod_items(value)? {
+            if let Item::Type(ty) = item {
+                if is_pallet(ty) {
+                    continue;
+                }
+                if ty.ident == "Assets" {
+                    assets = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetManager" {
+                    asset_manager = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetType" {
+                    asset_type = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "RuntimeOrigin" {
+                    runtime_origin = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AssetId" {
+                    asset_id = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AccountId" {
+                    account_id = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "Address" {
+                    address = errors.collect(fetch_type(&ty.ty))
+                }
+            }
+        }
+
+        let assets = errors.require(assets, &value.ident, "Assets");
+        let asset_manager = errors.require(asset_manager, &value.ident, "AssetManager");
+        let asset_type = errors.require(asset_type, &value.ident, "AssetType");
+        let runtime_origin = errors.require(runtime_origin, &value.ident, "RuntimeOrigin");
+        let asset_id = errors.require(asset_id, &value.ident, "AssetId");
+        let account_id = errors.require(account_id, &value.ident, "AccountId");
+        let address = errors.require(address, &value.ident, "Address");
+        let checked = errors.finish()?;
+        Ok(AssetsBenchmarkAPIFields {
+            assets: assets.get(&checked)?,
+            asset_manager: asset_manager.get(&checked)?,
+            asset_type: asset_type.get(&checked)?,
+            runtime_origin: runtime_origin.get(&checked)?,
+            asset_id: asset_id.get(&checked)?,
+            account_id: account_id.get(&checked)?,
+            address: address.get(&checked)?,
         })
     }
 }
 
 pub fn construct_benchmarking_api(
     consensus_benchmarking: bool,
-    runtime: &Ident,
+    runtime: &Type,
+    block: &Type,
     api_fields: BenchmarkAPIFields,
 ) -> proc_macro2::TokenStream {
     let mut xcm_dispatch = quote! {};
@@ -173,59 +374,73 @@ pub fn construct_benchmarking_api(
     let mut consensus_dispatch = quote! {};
     let mut consensus_metadata = quote! {};
 
-    if consensus_benchmarking {
-        consensus_dispatch = construct_consensus_dispatch_benchmarking(runtime);
-        consensus_metadata = construct_consensus_metadata_benchmarking();
-    }
-
     let BenchmarkAPIFields {
         all_pallets_with_system,
         system,
         parachain_system,
+        imports,
+        mut pallets,
         xcm_fields,
     } = api_fields;
 
-    if let Some(XCMBenchmarkAPIFields {
-        assets,
-        asset_manager,
-        asset_type,
-        runtime_origin,
-        relay_location,
-        existential_deposit,
-        asset_id,
-        xcm_config,
-        account_id,
-        cents,
-        fee_asset_id,
-        transaction_byte_fee,
-        address,
-        balances,
-    }) = xcm_fields
-    {
+    // `pallet_xcm` is benchmarked only with the XCM benchmarking types
+    if xcm_fields.is_none() {
+        pallets.retain(|pallet| pallet.name != "PolkadotXcm");
+    }
+    // `SessionBench` requires the configuration of the session benchmarking pallet
+    if consensus_benchmarking || pallets.iter().any(|pallet| pallet.name == "Session") {
+        consensus_dispatch = construct_consensus_dispatch_benchmarking(runtime);
+        consensus_metadata = construct_consensus_metadata_benchmarking();
+    }
+
+    // Without declared pallets, the benchmarks are the ones of the `define_benchmarks!` of the runtime
+    let (list_benchmarks, add_benchmarks) = if pallets.is_empty() {
+        (
+            quote! { list_benchmarks!(list, extra); },
+            quote! { add_benchmarks!(params, batches); },
+        )
+    } else {
+        let cfgs: Vec<_> = pallets.iter().map(|pallet| &pallet.cfgs).collect();
+        let modules: Vec<_> = pallets.iter().map(|pallet| &pallet.module).collect();
+        let locations: Vec<_> = pallets
+            .iter()
+            .map(|pallet| pallet.location(runtime))
+            .collect();
+        (
+            quote! {
+                #(
+                    #(#cfgs)*
+                    frame_benchmarking::list_benchmark!(list, extra, #modules, #locations);
+                )*
+            },
+            quote! {
+                #(
+                    #(#cfgs)*
+                    frame_benchmarking::add_benchmark!(params, batches, #modules, #locations);
+                )*
+            },
+        )
+    };
+
+    // `list_benchmarks!`, `add_benchmarks!` and the types they refer to are expected in the modules
+    // of the runtime templates, unless `mod benchmarks` imports them
+    let (metadata_imports, dispatch_imports) = if imports.is_empty() {
+        (
+            quote! { use crate::*; },
+            quote! { use crate::{*, types::*, configs::*}; },
+        )
+    } else {
+        (quote! { #(#imports)* }, quote! { #(#imports)* })
+    };
+
+    if let Some(xcm_fields) = xcm_fields {
         xcm_metadata = construct_xcm_metadata_benchmarking();
-        xcm_dispatch = construct_xcm_dispatch_benchmarking(
-            runtime,
-            assets,
-            asset_manager,
-            asset_type,
-            runtime_origin,
-            relay_location,
-            &parachain_system,
-            existential_deposit,
-            asset_id,
-            xcm_config,
-            account_id,
-            cents,
-            fee_asset_id,
-            transaction_byte_fee,
-            address,
-            balances,
-        );
+        xcm_dispatch = construct_xcm_dispatch_benchmarking(runtime, &parachain_system, xcm_fields);
     }
 
     quote! {
         #[cfg(feature = "runtime-benchmarks")]
-        impl frame_benchmarking::Benchmark<Block> for #runtime {
+        impl frame_benchmarking::Benchmark<#block> for #runtime {
             fn benchmark_metadata(extra: bool) -> (
                 sp_std::prelude::Vec<frame_benchmarking::BenchmarkList>,
                 sp_std::prelude::Vec<frame_support::traits::StorageInfo>,
@@ -233,15 +448,15 @@ pub fn construct_benchmarking_api(
                 use frame_benchmarking::{Benchmarking, BenchmarkList};
                 use frame_support::traits::StorageInfoTrait;
                 use frame_system_benchmarking::Pallet as SystemBench;
-                use crate::*;
+                #metadata_imports
 
                 #xcm_metadata
                 #consensus_metadata
 
                 let mut list = sp_std::prelude::Vec::<BenchmarkList>::new();
-                list_benchmarks!(list, extra);
+                #list_benchmarks
 
-                let storage_info = #all_pallets_with_system::storage_info();
+                let storage_info = <#all_pallets_with_system>::storage_info();
                 (list, storage_info)
             }
 
@@ -252,19 +467,19 @@ pub fn construct_benchmarking_api(
                 use frame_system_benchmarking::Pallet as SystemBench;
                 use pallet_xcm::benchmarking::Pallet as PalletXcmExtrinsicsBenchmark;
 
-                use crate::{*, types::*, configs::*};
+                #dispatch_imports
 
                 #[cfg(feature = "runtime-benchmarks")]
                 impl frame_system_benchmarking::Config for #runtime {
                     fn setup_set_code_requirements(
                         code: &sp_std::vec::Vec<u8>,
                     ) -> Result<(), BenchmarkError> {
-                        #parachain_system::initialize_for_set_code_benchmark(code.len() as u32);
+                        <#parachain_system>::initialize_for_set_code_benchmark(code.len() as u32);
                         Ok(())
                     }
 
                     fn verify_set_code() {
-                        #system::assert_last_event(
+                        <#system>::assert_last_event(
                             cumulus_pallet_parachain_system::Event::<#runtime>::ValidationFunctionStored
                                 .into(),
                         );
@@ -275,11 +490,11 @@ pub fn construct_benchmarking_api(
                 #consensus_dispatch
 
                 use frame_support::traits::WhitelistedStorageKeys;
-                let whitelist = #all_pallets_with_system::whitelisted_storage_keys();
+                let whitelist = <#all_pallets_with_system>::whitelisted_storage_keys();
 
                 let mut batches = sp_std::prelude::Vec::<BenchmarkBatch>::new();
                 let params = (&config, &whitelist);
-                add_benchmarks!(params, batches);
+                #add_benchmarks
 
                 if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
                 Ok(batches)
@@ -294,7 +509,7 @@ fn construct_consensus_metadata_benchmarking() -> proc_macro2::TokenStream {
     }
 }
 
-fn construct_consensus_dispatch_benchmarking(runtime: &Ident) -> proc_macro2::TokenStream {
+fn construct_consensus_dispatch_benchmarking(runtime: &Type) -> proc_macro2::TokenStream {
     quote! {
         use cumulus_pallet_session_benchmarking::Pallet as SessionBench;
         impl cumulus_pallet_session_benchmarking::Config for #runtime {}
@@ -307,40 +522,66 @@ fn construct_xcm_metadata_benchmarking() -> proc_macro2::TokenStream {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
 fn construct_xcm_dispatch_benchmarking(
-    runtime: &Ident,
-    assets: Ident,
-    asset_manager: Ident,
-    asset_type: Ident,
-    runtime_origin: Ident,
-    relay_location: Ident,
-    parachain_system: &Ident,
-    existential_deposit: Ident,
-    asset_id: Ident,
-    xcm_config: Ident,
-    account_id: Ident,
-    cents: Ident,
-    fee_asset_id: Ident,
-    transaction_byte_fee: Ident,
-    address: Ident,
-    balances: Ident,
+    runtime: &Type,
+    parachain_system: &Type,
+    xcm_fields: XCMBenchmarkAPIFields,
 ) -> proc_macro2::TokenStream {
+    let XCMBenchmarkAPIFields {
+        relay_location,
+        existential_deposit,
+        xcm_config,
+        cents,
+        fee_asset_id,
+        transaction_byte_fee,
+        balances,
+        transfers,
+    } = xcm_fields;
+
+    let (asset_items, transfers) = match transfers {
+        XCMBenchmarkTransfers::ForeignAssets(asset_fields) => (
+            quote! {
+                use xcm::latest::prelude::{GeneralIndex, PalletInstance};
+
+                parameter_types! {
+                    pub const InitialTransferAssetAmount: u128 = 4001070000100;
+                }
+            },
+            construct_foreign_assets_transfers(
+                runtime,
+                parachain_system,
+                &relay_location,
+                &existential_deposit,
+                &balances,
+                asset_fields,
+            ),
+        ),
+        XCMBenchmarkTransfers::Native { native_location } => (
+            quote! {},
+            construct_native_transfers(
+                parachain_system,
+                &existential_deposit,
+                &balances,
+                &native_location,
+            ),
+        ),
+    };
+
     quote! {
         use cumulus_primitives_core::ParaId;
         use frame_support::parameter_types;
-        use xcm::latest::prelude::{Asset, AssetId as XcmAssetId, Assets as AssetList, Fungible, Location, Parachain, Parent, ParentThen, PalletInstance, GeneralIndex};
+        use xcm::latest::prelude::{Asset, AssetId as XcmAssetId, Assets as AssetList, Fungible, Location, Parachain, Parent, ParentThen};
 
         parameter_types! {
             pub const RandomParaId: ParaId = ParaId::new(43211234);
             pub ExistentialDepositAsset: Option<Asset> = Some((
-                #relay_location::get(),
+                <#relay_location>::get(),
                 <#existential_deposit as sp_core::Get<u128>>::get()
             ).into());
             /// The base fee for the message delivery fees. Kusama is based for the reference.
             pub const ToParentBaseDeliveryFee: u128 = #cents.saturating_mul(3);
-            pub const InitialTransferAssetAmount: u128 = 4001070000100;
         }
+        #asset_items
 
         pub type PriceForParentDelivery = polkadot_runtime_common::xcm_sender::ExponentialPrice<
             #fee_asset_id,
@@ -364,138 +605,227 @@ fn construct_xcm_dispatch_benchmarking(
                 None
             }
 
-            fn reserve_transferable_asset_and_dest() -> Option<(Asset, Location)> {
-                use frame_support::traits::PalletInfoAccess;
-                use xcm_primitives::AssetTypeGetter;
-                use frame_system::RawOrigin;
-
-                // set up fee asset
-                let fee_location = #relay_location::get();
-                let who: #account_id = frame_benchmarking::whitelisted_caller();
-
-                let Some(location_v3) = xcm::v3::Location::try_from(fee_location.clone()).ok() else {
-                    return None;
-                };
-                let asset_type = #asset_type::Xcm(location_v3);
-
-                let balance = 3001070000000;
-                let who = frame_benchmarking::whitelisted_caller();
-                let _ =
-                    <#balances as frame_support::traits::Currency<_>>::make_free_balance_be(&who, balance);
-
-                let local_asset_id: #asset_id = asset_type.clone().into();
-                let manager_id = #asset_manager::account_id();
-                let _ = #assets::force_create(#runtime_origin::root(), local_asset_id.clone().into(), #address::from(manager_id.clone()), true, 1);
-                let _ = #assets::mint(
-                    RawOrigin::Signed(manager_id.clone()).into(),
-                    local_asset_id.into(),
-                    #address::from(who),
-                    InitialTransferAssetAmount::get(),
-                );
-                #asset_manager::set_asset_type_asset_id(asset_type.clone(), local_asset_id.into());
-
-                // open a mock parachain channel
-                #parachain_system::open_outbound_hrmp_channel_for_benchmarks_or_tests(
-                    RandomParaId::get().into()
-                );
-
-                // set up transfer asset
-                let initial_asset_amount: u128 = InitialTransferAssetAmount::get();
-                let (asset_id, _, _) = pallet_assets::benchmarking::create_default_minted_asset::<
-                    #runtime,
-                    ()
-                >(true, initial_asset_amount);
-
-                let local_asset_id: #asset_id = asset_id.into();
-                let self_reserve = Location {
-                    parents: 0,
-                    interior: [
-                        PalletInstance(<#assets as PalletInfoAccess>::index() as u8), GeneralIndex(local_asset_id as u128)
-                    ].into()
-                };
-
-                let Some(location_v3) = xcm::v3::Location::try_from(self_reserve.clone()).ok() else {
-                    return None;
-                };
-                let asset_type = #asset_type::Xcm(location_v3);
-                #asset_manager::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
-
-                let asset = Asset {
-                    fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
-                    id: XcmAssetId(self_reserve.into())
-                }.into();
-                Some((
-                    asset,
-                    ParentThen(Parachain(RandomParaId::get().into()).into()).into(),
-                ))
-            }
+            #transfers
+        }
+    }
+}
 
-            fn set_up_complex_asset_transfer(
-            ) -> Option<(AssetList, u32, Location, Box<dyn FnOnce()>)> {
-                use frame_support::traits::PalletInfoAccess;
-                use xcm_primitives::AssetTypeGetter;
-                // set up local asset
-                let initial_asset_amount: u128 = 1000000011;
-
-                let (asset_id, _, _) = pallet_assets::benchmarking::create_default_minted_asset::<
-                    #runtime,
-                    ()
-                >(true, initial_asset_amount);
-
-                let local_asset_id: #asset_id = asset_id.into();
-
-                let self_reserve = Location {
-                    parents:0,
-                    interior: [
-                        PalletInstance(<#assets as PalletInfoAccess>::index() as u8), GeneralIndex(local_asset_id as u128)
-                    ].into()
-                };
-
-                let Some(location_v3) = xcm::v3::Location::try_from(self_reserve.clone()).ok() else {
-                    return None;
-                };
-                let asset_type = #asset_type::Xcm(location_v3);
-                #asset_manager::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
-
-                let destination: xcm::v4::Location = Parent.into();
-
-                // set up fee asset
-                let fee_amount: u128 = <#existential_deposit as sp_core::Get<u128>>::get();
-                let asset_amount: u128 = 10;
-                let fee_asset: Asset = (self_reserve.clone(), fee_amount).into();
-                let transfer_asset: Asset = (self_reserve.clone(), asset_amount).into();
-
-                let assets: cumulus_primitives_core::Assets = sp_std::vec![fee_asset.clone(), transfer_asset].into();
-                let fee_index: u32 = 0;
-
-                let who = frame_benchmarking::whitelisted_caller();
-
-                let verify: Box<dyn FnOnce()> = Box::new(move || {
-                    // verify balance after transfer, decreased by
-                    // transferred amount (and delivery fees)
-                    assert!(#assets::balance(local_asset_id, &who) <= initial_asset_amount - fee_amount);
-                });
-
-                Some((assets, fee_index, destination, verify))
-            }
+/// Transfers of foreign assets registered in `pallet_asset_manager` and of local assets.
+fn construct_foreign_assets_transfers(
+    runtime: &Type,
+    parachain_system: &Type,
+    relay_location: &Type,
+    existential_deposit: &Type,
+    balances: &Type,
+    asset_fields: AssetsBenchmarkAPIFields,
+) -> proc_macro2::TokenStream {
+    let AssetsBenchmarkAPIFields {
+        assets,
+        asset_manager,
+        asset_type,
+        runtime_origin,
+        asset_id,
+        account_id,
+        address,
+    } = asset_fields;
+
+    quote! {
+        fn reserve_transferable_asset_and_dest() -> Option<(Asset, Location)> {
+            use frame_support::traits::PalletInfoAccess;
+            use xcm_primitives::AssetTypeGetter;
+            use frame_system::RawOrigin;
+
+            // set up fee asset
+            let fee_location = <#relay_location>::get();
+            let who: #account_id = frame_benchmarking::whitelisted_caller();
+
+            let Some(location_v3) = xcm::v3::Location::try_from(fee_location.clone()).ok() else {
+                return None;
+            };
+            let asset_type = <#asset_type>::Xcm(location_v3);
+
+            let balance = 3001070000000;
+            let who = frame_benchmarking::whitelisted_caller();
+            let _ =
+                <#balances as frame_support::traits::Currency<_>>::make_free_balance_be(&who, balance);
+
+            let local_asset_id: #asset_id = asset_type.clone().into();
+            let manager_id = <#asset_manager>::account_id();
+            let _ = <#assets>::force_create(<#runtime_origin>::root(), local_asset_id.clone().into(), <#address>::from(manager_id.clone()), true, 1);
+            let _ = <#assets>::mint(
+                RawOrigin::Signed(manager_id.clone()).into(),
+                local_asset_id.into(),
+                <#address>::from(who),
+                InitialTransferAssetAmount::get(),
+            );
+            <#asset_manager>::set_asset_type_asset_id(asset_type.clone(), local_asset_id.into());
+
+            // open a mock parachain channel
+            <#parachain_system>::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+                RandomParaId::get().into()
+            );
+
+            // set up transfer asset
+            let initial_asset_amount: u128 = InitialTransferAssetAmount::get();
+            let (asset_id, _, _) = pallet_assets::benchmarking::create_default_minted_asset::<
+                #runtime,
+                ()
+            >(true, initial_asset_amount);
+
+            let local_asset_id: #asset_id = asset_id.into();
+            let self_reserve = Location {
+                parents: 0,
+                interior: [
+                    PalletInstance(<#assets as PalletInfoAccess>::index() as u8), GeneralIndex(local_asset_id as u128)
+                ].into()
+            };
+
+            let Some(location_v3) = xcm::v3::Location::try_from(self_reserve.clone()).ok() else {
+                return None;
+            };
+            let asset_type = <#asset_type>::Xcm(location_v3);
+            <#asset_manager>::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
+
+            let asset = Asset {
+                fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
+                id: XcmAssetId(self_reserve.into())
+            }.into();
+            Some((
+                asset,
+                ParentThen(Parachain(RandomParaId::get().into()).into()).into(),
+            ))
+        }
+
+        fn set_up_complex_asset_transfer(
+        ) -> Option<(AssetList, u32, Location, Box<dyn FnOnce()>)> {
+            use frame_support::traits::PalletInfoAccess;
+            use xcm_primitives::AssetTypeGetter;
+            // set up local asset
+            let initial_asset_amount: u128 = 1000000011;
+
+            let (asset_id, _, _) = pallet_assets::benchmarking::create_default_minted_asset::<
+                #runtime,
+                ()
+            >(true, initial_asset_amount);
+
+            let local_asset_id: #asset_id = asset_id.into();
+
+            let self_reserve = Location {
+                parents:0,
+                interior: [
+                    PalletInstance(<#assets as PalletInfoAccess>::index() as u8), GeneralIndex(local_asset_id as u128)
+                ].into()
+            };
+
+            let Some(location_v3) = xcm::v3::Location::try_from(self_reserve.clone()).ok() else {
+                return None;
+            };
+            let asset_type = <#asset_type>::Xcm(location_v3);
+            <#asset_manager>::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
+
+            let destination: xcm::v4::Location = Parent.into();
+
+            // set up fee asset
+            let fee_amount: u128 = <#existential_deposit as sp_core::Get<u128>>::get();
+            let asset_amount: u128 = 10;
+            let fee_asset: Asset = (self_reserve.clone(), fee_amount).into();
+            let transfer_asset: Asset = (self_reserve.clone(), asset_amount).into();
+
+            let assets: cumulus_primitives_core::Assets = sp_std::vec![fee_asset.clone(), transfer_asset].into();
+            let fee_index: u32 = 0;
+
+            let who = frame_benchmarking::whitelisted_caller();
+
+            let verify: Box<dyn FnOnce()> = Box::new(move || {
+                // verify balance after transfer, decreased by
+                // transferred amount (and delivery fees)
+                assert!(<#assets>::balance(local_asset_id, &who) <= initial_asset_amount - fee_amount);
+            });
+
+            Some((assets, fee_index, destination, verify))
+        }
+
+        fn get_asset() -> Asset {
+            use xcm_primitives::AssetTypeGetter;
+            let location = Location::parent();
+            let asset_id = XcmAssetId(location.clone());
+            let asset = Asset {
+                id: asset_id.clone(),
+                fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
+            };
+            let Some(location_v3) = xcm::v3::Location::try_from(location).ok() else {
+                return asset;
+            };
+            let asset_type = <#asset_type>::Xcm(location_v3);
+            let local_asset_id: #asset_id = asset_type.clone().into();
+            let manager_id = <#asset_manager>::account_id();
+            let _ = <#assets>::force_create(<#runtime_origin>::root(), local_asset_id.clone().into(), <#address>::from(manager_id), true, 1);
+            <#asset_manager>::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
+            asset
+        }
+    }
+}
+
+/// Transfers of the native currency, reserved in this parachain, for runtimes without `pallet_asset_manager`.
+fn construct_native_transfers(
+    parachain_system: &Type,
+    existential_deposit: &Type,
+    balances: &Type,
+    native_location: &Type,
+) -> proc_macro2::TokenStream {
+    quote! {
+        fn reserve_transferable_asset_and_dest() -> Option<(Asset, Location)> {
+            // open a mock parachain channel
+            <#parachain_system>::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+                RandomParaId::get().into()
+            );
+
+            // the native currency is deposited by the benchmark at the location matched by the asset transactors
+            let asset = Asset {
+                fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
+                id: XcmAssetId(<#native_location as sp_core::Get<Location>>::get())
+            };
+            Some((
+                asset,
+                ParentThen(Parachain(RandomParaId::get().into()).into()).into(),
+            ))
+        }
+
+        fn set_up_complex_asset_transfer(
+        ) -> Option<(AssetList, u32, Location, Box<dyn FnOnce()>)> {
+            use frame_support::traits::Currency;
+
+            // Without `pallet_assets` the runtime holds no asset besides the native currency, relay chain tokens
+            // included, so the most complex transfer it makes is a reserve transfer of the native currency paying
+            // its own fees
+            <#parachain_system>::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+                RandomParaId::get().into()
+            );
+            let destination: Location = ParentThen(Parachain(RandomParaId::get().into()).into()).into();
+
+            let fee_amount: u128 = <#existential_deposit as sp_core::Get<u128>>::get();
+            let asset_amount: u128 = fee_amount.saturating_mul(10);
+            let initial_balance: u128 = fee_amount.saturating_mul(1000);
+            let who = frame_benchmarking::whitelisted_caller();
+            let _ = <#balances as Currency<_>>::make_free_balance_be(&who, initial_balance);
+
+            let asset: Asset = (<#native_location as sp_core::Get<Location>>::get(), asset_amount).into();
+            let assets: AssetList = asset.into();
+            let fee_index: u32 = 0;
+
+            let verify: Box<dyn FnOnce()> = Box::new(move || {
+                // verify balance after transfer, decreased by
+                // transferred amount (and delivery fees)
+                assert!(<#balances as Currency<_>>::free_balance(&who) <= initial_balance - asset_amount);
+            });
+
+            Some((assets, fee_index, destination, verify))
+        }
 
-            fn get_asset() -> Asset {
-                use xcm_primitives::AssetTypeGetter;
-                let location = Location::parent();
-                let asset_id = XcmAssetId(location.clone());
-                let asset = Asset {
-                    id: asset_id.clone(),
-                    fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
-                };
-                let Some(location_v3) = xcm::v3::Location::try_from(location).ok() else {
-                    return asset;
-                };
-                let asset_type = #asset_type::Xcm(location_v3);
-                let local_asset_id: #asset_id = asset_type.clone().into();
-                let manager_id = #asset_manager::account_id();
-                let _ = #assets::force_create(#runtime_origin::root(), local_asset_id.clone().into(), #address::from(manager_id), true, 1);
-                #asset_manager::set_asset_type_asset_id(asset_type.clone(), local_asset_id);
-                asset
+        fn get_asset() -> Asset {
+            Asset {
+                id: XcmAssetId(<#native_location as sp_core::Get<Location>>::get()),
+                fun: Fungible(<#existential_deposit as sp_core::Get<u128>>::get()),
             }
         }
     }
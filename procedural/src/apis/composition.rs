@@ -0,0 +1,14 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+/// Implements `OpenZeppelinRuntimeApi`, returning `RUNTIME_COMPOSITION` generated by `openzeppelin_construct_runtime`.
+pub fn composition_apis(runtime: &Type, block: &Type) -> TokenStream {
+    quote! {
+        impl openzeppelin_pallet_abstractions::runtime_api::OpenZeppelinRuntimeApi<#block> for #runtime {
+            fn runtime_composition() -> sp_std::prelude::Vec<openzeppelin_pallet_abstractions::runtime_api::AbstractionInfo> {
+                RUNTIME_COMPOSITION.to_vec()
+            }
+        }
+    }
+}
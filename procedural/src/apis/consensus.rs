@@ -1,94 +1,203 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Item};
+use syn::{parse_quote, Item, ItemMod, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
 
 #[derive(Debug)]
 pub struct ConsensusAPIFields {
-    pub session_keys: Ident,
-    #[cfg(not(feature = "async-backing"))]
-    pub aura: Ident,
-    #[cfg(feature = "async-backing")]
-    pub slot_duration: Ident,
-    #[cfg(feature = "async-backing")]
-    pub consensus_hook: Ident,
+    pub session_keys: Type,
+    /// The declared `ConsensusMode` type, checked against the `SystemConfig::ConsensusMode` of the runtime
+    pub mode_type: Type,
+    pub mode: ConsensusMode,
 }
 
-impl TryFrom<&[Item]> for ConsensusAPIFields {
-    type Error = &'static str;
+/// Block production mode declared by `type ConsensusMode`, with the types it requires.
+///
+/// It has to match the `SystemConfig::ConsensusMode` of the runtime.
+#[derive(Debug)]
+pub enum ConsensusMode {
+    SyncBacking {
+        aura: Type,
+    },
+    AsyncBacking(Box<UnincludedSegment>),
+    ElasticScaling {
+        unincluded_segment: Box<UnincludedSegment>,
+        parachain_system: Type,
+    },
+}
 
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
-        let mut session_keys = None;
+/// Types of the modes building parachain blocks on top of unincluded ones.
+#[derive(Debug)]
+pub struct UnincludedSegment {
+    pub slot_duration: Type,
+    pub consensus_hook: Type,
+}
+
+/// Modes, named by the last segment of the `ConsensusMode` path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ModeName {
+    SyncBacking,
+    AsyncBacking,
+    ElasticScaling,
+}
+
+const MODES: [(&str, ModeName); 3] = [
+    ("SyncBacking", ModeName::SyncBacking),
+    ("AsyncBacking", ModeName::AsyncBacking),
+    ("ElasticScaling", ModeName::ElasticScaling),
+];
+
+impl TryFrom<&ItemMod> for ConsensusAPIFields {
+    type Error = syn::Error;
 
-        #[cfg(not(feature = "async-backing"))]
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
+        let mut session_keys = None;
+        let mut mode = None;
         let mut aura = None;
-        #[cfg(feature = "async-backing")]
         let mut slot_duration = None;
-        #[cfg(feature = "async-backing")]
         let mut consensus_hook = None;
+        let mut parachain_system = None;
 
-        for item in value {
+        for item in mod_items(value)? {
             if let Item::Type(ty) = item {
-                let typ = ty.ty.clone();
                 if ty.ident == "SessionKeys" {
-                    session_keys = Some(fetch_ident(&typ))
-                }
-
-                #[cfg(not(feature = "async-backing"))]
-                if ty.ident == "Aura" {
-                    aura = Some(fetch_ident(&typ))
-                }
-
-                #[cfg(feature = "async-backing")]
-                if ty.ident == "SlotDuration" {
-                    slot_duration = Some(fetch_ident(&typ))
+                    session_keys = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "ConsensusMode" {
+                    mode = errors.collect(fetch_mode(&ty.ty))
+                } else if ty.ident == "Aura" {
+                    aura = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "SlotDuration" {
+                    slot_duration = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "ConsensusHook" {
-                    consensus_hook = Some(fetch_ident(&typ))
+                    consensus_hook = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "ParachainSystem" {
+                    parachain_system = errors.collect(fetch_type(&ty.ty))
                 }
             }
         }
-        let session_keys = session_keys.ok_or("type `SessionKeys` not specified, but required")?;
-
-        #[cfg(not(feature = "async-backing"))]
-        {
-            let aura = aura.ok_or("type `Aura` not specified, but required")?;
-            Ok(ConsensusAPIFields { session_keys, aura })
-        }
-
-        #[cfg(feature = "async-backing")]
-        {
-            let slot_duration =
-                slot_duration.ok_or("type `SlotDuration` not specified, but required")?;
-            let consensus_hook =
-                consensus_hook.ok_or("type `ConsensusHook` not specified, but required")?;
-            Ok(ConsensusAPIFields {
-                session_keys,
-                slot_duration,
-                consensus_hook,
-            })
+        let session_keys = errors.require(session_keys, &value.ident, "SessionKeys");
+
+        // Synchronous backing is the default of `SystemConfig`
+        let (mode_type, mode) = mode.unwrap_or_else(|| {
+            (
+                parse_quote!(openzeppelin_pallet_abstractions::SyncBacking),
+                ModeName::SyncBacking,
+            )
+        });
+        match mode {
+            ModeName::SyncBacking => {
+                let aura = errors.require(aura, &value.ident, "Aura");
+                let checked = errors.finish()?;
+                Ok(ConsensusAPIFields {
+                    session_keys: session_keys.get(&checked)?,
+                    mode_type,
+                    mode: ConsensusMode::SyncBacking {
+                        aura: aura.get(&checked)?,
+                    },
+                })
+            }
+            ModeName::AsyncBacking => {
+                let slot_duration = errors.require(slot_duration, &value.ident, "SlotDuration");
+                let consensus_hook = errors.require(consensus_hook, &value.ident, "ConsensusHook");
+                let checked = errors.finish()?;
+                Ok(ConsensusAPIFields {
+                    session_keys: session_keys.get(&checked)?,
+                    mode_type,
+                    mode: ConsensusMode::AsyncBacking(Box::new(UnincludedSegment {
+                        slot_duration: slot_duration.get(&checked)?,
+                        consensus_hook: consensus_hook.get(&checked)?,
+                    })),
+                })
+            }
+            ModeName::ElasticScaling => {
+                let slot_duration = errors.require(slot_duration, &value.ident, "SlotDuration");
+                let consensus_hook = errors.require(consensus_hook, &value.ident, "ConsensusHook");
+                let parachain_system =
+                    errors.require(parachain_system, &value.ident, "ParachainSystem");
+                let checked = errors.finish()?;
+                Ok(ConsensusAPIFields {
+                    session_keys: session_keys.get(&checked)?,
+                    mode_type,
+                    mode: ConsensusMode::ElasticScaling {
+                        unincluded_segment: Box::new(UnincludedSegment {
+                            slot_duration: slot_duration.get(&checked)?,
+                            consensus_hook: consensus_hook.get(&checked)?,
+                        }),
+                        parachain_system: parachain_system.get(&checked)?,
+                    },
+                })
+            }
         }
     }
 }
 
+/// Returns the type along with the mode it refers to, e.g. `AsyncBacking` for
+/// `openzeppelin_pallet_abstractions::AsyncBacking`.
+fn fetch_mode(ty: &Type) -> syn::Result<(Type, ModeName)> {
+    let ty = fetch_type(ty)?;
+    let name = match &ty {
+        Type::Path(path) => path.path.segments.last().map(|segment| &segment.ident),
+        _ => None,
+    };
+    let mode = MODES
+        .into_iter()
+        .find(|(mode, _)| name.is_some_and(|name| name == mode))
+        .map(|(_, mode)| mode)
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                &ty,
+                "expected `SyncBacking`, `AsyncBacking` or `ElasticScaling`",
+            )
+        })?;
+    Ok((ty, mode))
+}
+
 pub fn consensus_apis(
-    runtime: &Ident,
-    block: &Ident,
-    session_keys: &Ident,
-    #[cfg(not(feature = "async-backing"))] aura: &Ident,
-    #[cfg(feature = "async-backing")] slot_duration: &Ident,
-    #[cfg(feature = "async-backing")] consensus_hook: &Ident,
+    runtime: &Type,
+    block: &Type,
+    session_keys: &Type,
+    mode_type: &Type,
+    mode: &ConsensusMode,
 ) -> TokenStream {
-    #[cfg(feature = "async-backing")]
-    let slot_duration = quote! {
-        return sp_consensus_aura::SlotDuration::from_millis(#slot_duration);
+    // The slot duration is read from Aura, unless blocks are built on top of unincluded ones
+    let fixed_slot_duration = |UnincludedSegment { slot_duration, .. }: &UnincludedSegment| {
+        quote! {
+            return sp_consensus_aura::SlotDuration::from_millis(#slot_duration);
+        }
     };
-    #[cfg(not(feature = "async-backing"))]
-    let slot_duration = quote! {
-        sp_consensus_aura::SlotDuration::from_millis(#aura::slot_duration())
+    let (slot_duration, unincluded_segment, parachain_system) = match mode {
+        ConsensusMode::SyncBacking { aura } => (
+            quote! {
+                sp_consensus_aura::SlotDuration::from_millis(<#aura>::slot_duration())
+            },
+            None,
+            None,
+        ),
+        ConsensusMode::AsyncBacking(unincluded_segment) => (
+            fixed_slot_duration(unincluded_segment),
+            Some(unincluded_segment),
+            None,
+        ),
+        ConsensusMode::ElasticScaling {
+            unincluded_segment,
+            parachain_system,
+        } => (
+            fixed_slot_duration(unincluded_segment),
+            Some(unincluded_segment),
+            Some(parachain_system),
+        ),
     };
 
-    let mut res = quote! {};
+    // The mode defaults to `SyncBacking`, a different configured mode is reported along with the declaration to add
+    let mut res = quote! {
+        const _: () = openzeppelin_pallet_abstractions::check_consensus_mode::<
+            <#runtime as openzeppelin_pallet_abstractions::ConfiguredConsensusMode>::ConsensusMode,
+            #mode_type,
+        >();
+    };
 
     res.extend(quote! {
         impl sp_consensus_aura::AuraApi<#block, sp_consensus_aura::sr25519::AuthorityId> for #runtime {
@@ -103,25 +212,35 @@ pub fn consensus_apis(
 
         impl sp_session::SessionKeys<#block> for #runtime {
             fn generate_session_keys(seed: Option<sp_std::prelude::Vec<u8>>) -> sp_std::prelude::Vec<u8> {
-                #session_keys::generate(seed)
+                <#session_keys>::generate(seed)
             }
 
             fn decode_session_keys(encoded: sp_std::prelude::Vec<u8>) -> Option<sp_std::prelude::Vec<(sp_std::prelude::Vec<u8>, sp_core::crypto::KeyTypeId)>> {
-                #session_keys::decode_into_raw_public_keys(&encoded)
+                <#session_keys>::decode_into_raw_public_keys(&encoded)
             }
         }
     });
-    #[cfg(feature = "async-backing")]
-    res.extend(quote! {
-        impl cumulus_primitives_aura::AuraUnincludedSegmentApi<#block> for #runtime {
-            fn can_build_upon(
-                included_hash: <#block as sp_runtime::traits::Block>::Hash,
-                slot: cumulus_primitives_aura::Slot,
-            ) -> bool {
-                #consensus_hook::can_build_upon(included_hash, slot)
+    if let Some(UnincludedSegment { consensus_hook, .. }) = unincluded_segment.map(Box::as_ref) {
+        res.extend(quote! {
+            impl cumulus_primitives_aura::AuraUnincludedSegmentApi<#block> for #runtime {
+                fn can_build_upon(
+                    included_hash: <#block as sp_runtime::traits::Block>::Hash,
+                    slot: cumulus_primitives_aura::Slot,
+                ) -> bool {
+                    <#consensus_hook>::can_build_upon(included_hash, slot)
+                }
             }
-        }
-    });
+        });
+    }
+    if let Some(parachain_system) = parachain_system {
+        res.extend(quote! {
+            impl cumulus_primitives_core::GetCoreSelectorApi<#block> for #runtime {
+                fn core_selector() -> (cumulus_primitives_core::CoreSelector, cumulus_primitives_core::ClaimQueueOffset) {
+                    <#parachain_system>::core_selector()
+                }
+            }
+        });
+    }
 
     res
 }
@@ -1,53 +1,55 @@
 use proc_macro2::TokenStream;
-use syn::{Ident, Item};
+use syn::{Item, ItemMod, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
 
 #[derive(Debug)]
 pub struct EVMAPIFields {
-    pub call: Ident,
-    pub executive: Ident,
-    pub ethereum: Ident,
+    pub call: Type,
+    pub executive: Type,
+    pub ethereum: Type,
 }
 
-impl TryFrom<&[Item]> for EVMAPIFields {
-    type Error = &'static str;
+impl TryFrom<&ItemMod> for EVMAPIFields {
+    type Error = syn::Error;
 
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
         let mut call = None;
         let mut executive = None;
         let mut ethereum = None;
 
-        for item in value {
+        for item in mod_items(value)? {
             if let Item::Type(ty) = item {
                 if ty.ident == "RuntimeCall" {
-                    call = Some(fetch_ident(&ty.ty))
+                    call = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Executive" {
-                    executive = Some(fetch_ident(&ty.ty))
+                    executive = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Ethereum" {
-                    ethereum = Some(fetch_ident(&ty.ty))
+                    ethereum = errors.collect(fetch_type(&ty.ty))
                 }
             }
         }
 
-        let executive = executive.ok_or("`type Executive` not specified, but required")?;
-        let ethereum = ethereum.ok_or("`type Ethereum` not specified, but required")?;
-        let call = call.ok_or("`type Call` not specified, but required")?;
-
+        let executive = errors.require(executive, &value.ident, "Executive");
+        let ethereum = errors.require(ethereum, &value.ident, "Ethereum");
+        let call = errors.require(call, &value.ident, "RuntimeCall");
+        let checked = errors.finish()?;
         Ok(EVMAPIFields {
-            call,
-            executive,
-            ethereum,
+            call: call.get(&checked)?,
+            executive: executive.get(&checked)?,
+            ethereum: ethereum.get(&checked)?,
         })
     }
 }
 
 pub fn evm_apis(
-    runtime: &Ident,
-    block: &Ident,
-    runtime_call: &Ident,
-    executive: &Ident,
-    ethereum: &Ident,
+    runtime: &Type,
+    block: &Type,
+    runtime_call: &Type,
+    executive: &Type,
+    ethereum: &Type,
 ) -> TokenStream {
     quote::quote! {
         impl fp_rpc::EthereumRuntimeRPCApi<#block> for #runtime {
@@ -226,8 +228,10 @@ pub fn evm_apis(
                 xts: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
             ) -> sp_std::prelude::Vec<pallet_ethereum::Transaction> {
                 use pallet_ethereum::Call::transact;
+                // Qualified paths are not allowed in patterns, the call type is aliased instead
+                type Call = #runtime_call;
                 xts.into_iter().filter_map(|xt| match xt.0.function {
-                    #runtime_call::Ethereum(transact { transaction }) => Some(transaction),
+                    Call::Ethereum(transact { transaction }) => Some(transaction),
                     _ => None
                 }).collect::<sp_std::prelude::Vec<pallet_ethereum::Transaction>>()
             }
@@ -246,10 +250,10 @@ pub fn evm_apis(
                 xts: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
             ) -> (Option<pallet_ethereum::Block>, Option<sp_std::prelude::Vec<pallet_ethereum::TransactionStatus>>) {
                 for ext in xts.into_iter() {
-                    let _ = #executive::apply_extrinsic(ext);
+                    let _ = <#executive>::apply_extrinsic(ext);
                 }
 
-                #ethereum::on_finalize(System::block_number() + 1);
+                <#ethereum>::on_finalize(System::block_number() + 1);
 
                 (
                     pallet_ethereum::CurrentBlock::<#runtime>::get(),
@@ -258,7 +262,7 @@ pub fn evm_apis(
             }
 
             fn initialize_pending_block(header: &<#block as sp_runtime::traits::Block>::Header) {
-                #executive::initialize_block(header);
+                <#executive>::initialize_block(header);
             }
         }
 
@@ -0,0 +1,146 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Type;
+
+/// Implements the debug and transaction pool APIs used by EVM tracing, under the `evm-tracing` feature of the runtime.
+///
+/// The extrinsics are replayed with `Executive` and the Ethereum transactions among them are traced by the EVM tracer.
+pub fn evm_tracing_apis(
+    runtime: &Type,
+    block: &Type,
+    runtime_call: &Type,
+    executive: &Type,
+) -> TokenStream {
+    quote! {
+        #[cfg(feature = "evm-tracing")]
+        impl moonbeam_rpc_primitives_debug::DebugRuntimeApi<#block> for #runtime {
+            fn trace_transaction(
+                extrinsics: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
+                traced_transaction: &pallet_ethereum::Transaction,
+                header: &<#block as sp_runtime::traits::Block>::Header,
+            ) -> Result<(), sp_runtime::DispatchError> {
+                use moonbeam_evm_tracer::tracer::EvmTracer;
+                use pallet_ethereum::Call::transact;
+                // Qualified paths are not allowed in patterns, the call type is aliased instead
+                type Call = #runtime_call;
+
+                <#executive>::initialize_block(header);
+                // The extrinsics preceding the traced transaction are applied without tracing
+                for ext in extrinsics.into_iter() {
+                    let _ = match &ext.0.function {
+                        Call::Ethereum(transact { transaction }) if transaction == traced_transaction => {
+                            EvmTracer::new().trace(|| <#executive>::apply_extrinsic(ext));
+                            return Ok(());
+                        }
+                        _ => <#executive>::apply_extrinsic(ext),
+                    };
+                }
+                Err(sp_runtime::DispatchError::Other(
+                    "Failed to find Ethereum transaction among the extrinsics.",
+                ))
+            }
+
+            fn trace_block(
+                extrinsics: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
+                known_transactions: sp_std::prelude::Vec<sp_core::H256>,
+                header: &<#block as sp_runtime::traits::Block>::Header,
+            ) -> Result<(), sp_runtime::DispatchError> {
+                use moonbeam_evm_tracer::tracer::EvmTracer;
+                use pallet_ethereum::Call::transact;
+                type Call = #runtime_call;
+
+                <#executive>::initialize_block(header);
+                for ext in extrinsics.into_iter() {
+                    match &ext.0.function {
+                        Call::Ethereum(transact { transaction })
+                            if known_transactions.contains(&transaction.hash()) =>
+                        {
+                            // Each known transaction is traced as a new call stack
+                            EvmTracer::emit_new();
+                            EvmTracer::new().trace(|| <#executive>::apply_extrinsic(ext));
+                        }
+                        _ => {
+                            let _ = <#executive>::apply_extrinsic(ext);
+                        }
+                    };
+                }
+                Ok(())
+            }
+
+            fn trace_call(
+                header: &<#block as sp_runtime::traits::Block>::Header,
+                from: sp_core::H160,
+                to: sp_core::H160,
+                data: sp_std::prelude::Vec<u8>,
+                value: sp_core::U256,
+                gas_limit: sp_core::U256,
+                max_fee_per_gas: Option<sp_core::U256>,
+                max_priority_fee_per_gas: Option<sp_core::U256>,
+                nonce: Option<sp_core::U256>,
+                access_list: Option<sp_std::prelude::Vec<(sp_core::H160, sp_std::prelude::Vec<sp_core::H256>)>>,
+            ) -> Result<(), sp_runtime::DispatchError> {
+                use frame_support::pallet_prelude::Encode;
+                use moonbeam_evm_tracer::tracer::EvmTracer;
+                use pallet_evm::{GasWeightMapping, Runner};
+
+                <#executive>::initialize_block(header);
+                EvmTracer::new().trace(|| {
+                    // The transaction size is estimated from the heaviest transaction type (EIP-1559)
+                    let mut estimated_transaction_len = data.len() + 258;
+                    if access_list.is_some() {
+                        estimated_transaction_len += access_list.encoded_size();
+                    }
+                    let gas_limit = gas_limit.min(u64::MAX.into()).low_u64();
+                    let (weight_limit, proof_size_base_cost) =
+                        match <#runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(gas_limit, true) {
+                            weight_limit if weight_limit.proof_size() > 0 => {
+                                (Some(weight_limit), Some(estimated_transaction_len as u64))
+                            }
+                            _ => (None, None),
+                        };
+                    let _ = <#runtime as pallet_evm::Config>::Runner::call(
+                        from,
+                        to,
+                        data,
+                        value,
+                        gas_limit,
+                        max_fee_per_gas,
+                        max_priority_fee_per_gas,
+                        nonce,
+                        access_list.unwrap_or_default(),
+                        false,
+                        true,
+                        weight_limit,
+                        proof_size_base_cost,
+                        <#runtime as pallet_evm::Config>::config(),
+                    );
+                });
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "evm-tracing")]
+        impl moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi<#block> for #runtime {
+            fn extrinsic_filter(
+                xts_ready: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
+                xts_future: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>,
+            ) -> moonbeam_rpc_primitives_txpool::TxPoolResponse {
+                use pallet_ethereum::Call::transact;
+                type Call = #runtime_call;
+
+                let ethereum_transactions = |xts: sp_std::prelude::Vec<<#block as sp_runtime::traits::Block>::Extrinsic>| {
+                    xts.into_iter()
+                        .filter_map(|xt| match xt.0.function {
+                            Call::Ethereum(transact { transaction }) => Some(transaction),
+                            _ => None,
+                        })
+                        .collect()
+                };
+                moonbeam_rpc_primitives_txpool::TxPoolResponse {
+                    ready: ethereum_transactions(xts_ready),
+                    future: ethereum_transactions(xts_future),
+                }
+            }
+        }
+    }
+}
@@ -1,26 +1,40 @@
 mod assets;
 mod benchmark;
+mod composition;
 mod consensus;
 mod evm;
+mod evm_tracing;
 mod system;
 mod tanssi;
+mod xcm;
 
 pub use assets::*;
 pub use benchmark::*;
+pub use composition::*;
 pub use consensus::*;
 pub use evm::*;
+pub use evm_tracing::*;
 pub use system::*;
 pub use tanssi::*;
+pub use xcm::*;
 
-use syn::{Ident, Type};
+use syn::{Item, ItemMod, Type};
 
-pub fn fetch_ident(ty: &Type) -> Ident {
+pub fn fetch_type(ty: &Type) -> syn::Result<Type> {
     match ty {
-        Type::Path(p) => p
-            .path
-            .get_ident()
-            .unwrap_or_else(|| panic!("Wrong type received: {:?}", p))
-            .clone(),
-        _ => panic!("Wrong type received: {:?}", ty),
+        Type::Infer(_) | Type::ImplTrait(_) | Type::Never(_) => {
+            Err(syn::Error::new_spanned(ty, "expected a concrete type"))
+        }
+        ty => Ok(ty.clone()),
+    }
+}
+
+pub fn mod_items(item: &ItemMod) -> syn::Result<&[Item]> {
+    match &item.content {
+        Some((_, items)) => Ok(items),
+        None => Err(syn::Error::new_spanned(
+            item,
+            format!("`mod {}` does not have any content", item.ident),
+        )),
     }
 }
@@ -1,25 +1,40 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Item};
+use syn::{Item, ItemMod, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
 
 #[derive(Debug)]
 pub struct SystemAPIFields {
-    pub executive: Ident,
-    pub system: Ident,
-    pub parachain_system: Ident,
-    pub version: Ident,
-    pub account_id: Ident,
-    pub nonce: Ident,
-    pub genesis: Ident,
-    pub runtime_block_weights: Ident,
+    pub executive: Type,
+    pub system: Type,
+    pub parachain_system: Type,
+    pub version: Type,
+    pub account_id: Type,
+    pub nonce: Type,
+    pub genesis: Type,
+    pub runtime_block_weights: Type,
+    pub genesis_presets: GenesisPresets,
 }
 
-impl TryFrom<&[Item]> for SystemAPIFields {
-    type Error = &'static str;
+/// Presets returned by `sp_genesis_builder::GenesisBuilder`.
+///
+/// Without a `custom` module providing `get_preset` and `preset_names`, the `development` and `local_testnet`
+/// presets are generated for the pallets present in the runtime.
+#[derive(Debug)]
+pub struct GenesisPresets {
+    pub custom: Option<Type>,
+    pub dev_account_pair: Option<Type>,
+    pub parachain_id: Option<Type>,
+    pub evm_chain_id: Option<Type>,
+}
 
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
+impl TryFrom<&ItemMod> for SystemAPIFields {
+    type Error = syn::Error;
+
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
         let mut executive = None;
         let mut system = None;
         let mut parachain_system = None;
@@ -28,104 +43,131 @@ impl TryFrom<&[Item]> for SystemAPIFields {
         let mut nonce = None;
         let mut genesis = None;
         let mut runtime_block_weights = None;
+        let mut genesis_presets = None;
+        let mut dev_account_pair = None;
+        let mut parachain_id = None;
+        let mut evm_chain_id = None;
 
-        for item in value {
+        for item in mod_items(value)? {
             if let Item::Type(ty) = item {
                 if ty.ident == "Executive" {
-                    executive = Some(fetch_ident(&ty.ty))
+                    executive = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "System" {
-                    system = Some(fetch_ident(&ty.ty))
+                    system = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "ParachainSystem" {
-                    parachain_system = Some(fetch_ident(&ty.ty))
+                    parachain_system = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "RuntimeVersion" {
-                    version = Some(fetch_ident(&ty.ty))
+                    version = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "AccountId" {
-                    account_id = Some(fetch_ident(&ty.ty))
+                    account_id = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "Nonce" {
-                    nonce = Some(fetch_ident(&ty.ty))
+                    nonce = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "RuntimeGenesisConfig" {
-                    genesis = Some(fetch_ident(&ty.ty))
+                    genesis = errors.collect(fetch_type(&ty.ty))
                 } else if ty.ident == "RuntimeBlockWeights" {
-                    runtime_block_weights = Some(fetch_ident(&ty.ty))
+                    runtime_block_weights = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "GenesisPresets" {
+                    genesis_presets = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "DevAccountPair" {
+                    dev_account_pair = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "ParachainId" {
+                    parachain_id = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "EvmChainId" {
+                    evm_chain_id = errors.collect(fetch_type(&ty.ty))
                 }
             }
         }
 
-        let executive = executive.ok_or("`type Executive` not specified, but required")?;
-        let system = system.ok_or("`type System` not specified, but required")?;
-        let parachain_system =
-            parachain_system.ok_or("`type ParachainSystem` not specified, but required")?;
-        let version = version.ok_or("`type RuntimeVersion` not specified, but required")?;
-        let account_id = account_id.ok_or("`type AccountId` not specified, but required")?;
-        let nonce = nonce.ok_or("`type Nonce` not specified, but required")?;
-        let genesis = genesis.ok_or("`type RuntimeGenesisConfig` not specified, but required")?;
-        let runtime_block_weights = runtime_block_weights
-            .ok_or("`type RuntimeBlockWeights` not specified, but required")?;
-
+        let executive = errors.require(executive, &value.ident, "Executive");
+        let system = errors.require(system, &value.ident, "System");
+        let parachain_system = errors.require(parachain_system, &value.ident, "ParachainSystem");
+        let version = errors.require(version, &value.ident, "RuntimeVersion");
+        let account_id = errors.require(account_id, &value.ident, "AccountId");
+        let nonce = errors.require(nonce, &value.ident, "Nonce");
+        let genesis = errors.require(genesis, &value.ident, "RuntimeGenesisConfig");
+        let runtime_block_weights =
+            errors.require(runtime_block_weights, &value.ident, "RuntimeBlockWeights");
+        let checked = errors.finish()?;
+        let genesis_presets = GenesisPresets {
+            custom: genesis_presets,
+            dev_account_pair,
+            parachain_id,
+            evm_chain_id,
+        };
         Ok(SystemAPIFields {
-            executive,
-            system,
-            parachain_system,
-            version,
-            account_id,
-            nonce,
-            genesis,
-            runtime_block_weights,
+            executive: executive.get(&checked)?,
+            system: system.get(&checked)?,
+            parachain_system: parachain_system.get(&checked)?,
+            version: version.get(&checked)?,
+            account_id: account_id.get(&checked)?,
+            nonce: nonce.get(&checked)?,
+            genesis: genesis.get(&checked)?,
+            runtime_block_weights: runtime_block_weights.get(&checked)?,
+            genesis_presets,
         })
     }
 }
 
 #[allow(clippy::too_many_arguments)]
 pub fn system_apis(
-    runtime: &Ident,
-    block: &Ident,
-    executive: &Ident,
-    system: &Ident,
-    parachain_system: &Ident,
-    version: &Ident,
-    account_id: &Ident,
-    nonce: &Ident,
-    genesis: &Ident,
-    runtime_block_weights: &Ident,
+    runtime: &Type,
+    block: &Type,
+    executive: &Type,
+    system: &Type,
+    parachain_system: &Type,
+    version: &Type,
+    account_id: &Type,
+    nonce: &Type,
+    genesis: &Type,
+    runtime_block_weights: &Type,
+    genesis_presets: &GenesisPresets,
 ) -> TokenStream {
+    let (get_preset, preset_names) = match &genesis_presets.custom {
+        Some(presets) => (
+            quote!(#presets::get_preset),
+            quote!(#presets::preset_names()),
+        ),
+        None => generated_presets(genesis, account_id, genesis_presets),
+    };
+
     quote! {
-        impl sp_api::Core<Block> for #runtime {
+        impl sp_api::Core<#block> for #runtime {
             fn version() -> sp_version::RuntimeVersion {
                 #version
             }
 
             fn execute_block(block: #block) {
-                #executive::execute_block(block)
+                <#executive>::execute_block(block)
             }
 
             fn initialize_block(
                 header: &<#block as sp_runtime::traits::Block>::Header,
             ) -> sp_runtime::ExtrinsicInclusionMode {
-                #executive::initialize_block(header)
+                <#executive>::initialize_block(header)
             }
         }
 
         impl sp_api::Metadata<#block> for #runtime {
             fn metadata() -> sp_core::OpaqueMetadata {
-                sp_core::OpaqueMetadata::new(#runtime::metadata().into())
+                sp_core::OpaqueMetadata::new(<#runtime>::metadata().into())
             }
 
             fn metadata_at_version(version: u32) -> Option<sp_core::OpaqueMetadata> {
-                #runtime::metadata_at_version(version)
+                <#runtime>::metadata_at_version(version)
             }
 
             fn metadata_versions() -> sp_std::vec::Vec<u32> {
-                #runtime::metadata_versions()
+                <#runtime>::metadata_versions()
             }
         }
 
         impl sp_block_builder::BlockBuilder<#block> for #runtime {
             fn apply_extrinsic(extrinsic: <#block as sp_runtime::traits::Block>::Extrinsic) -> sp_runtime::ApplyExtrinsicResult {
-                #executive::apply_extrinsic(extrinsic)
+                <#executive>::apply_extrinsic(extrinsic)
             }
 
             fn finalize_block() -> <#block as sp_runtime::traits::Block>::Header {
-                #executive::finalize_block()
+                <#executive>::finalize_block()
             }
 
             fn inherent_extrinsics(
@@ -148,19 +190,19 @@ pub fn system_apis(
                 tx: <#block as sp_runtime::traits::Block>::Extrinsic,
                 block_hash: <#block as sp_runtime::traits::Block>::Hash,
             ) -> sp_runtime::transaction_validity::TransactionValidity {
-                #executive::validate_transaction(source, tx, block_hash)
+                <#executive>::validate_transaction(source, tx, block_hash)
             }
         }
 
         impl sp_offchain::OffchainWorkerApi<#block> for #runtime {
             fn offchain_worker(header: &<#block as sp_runtime::traits::Block>::Header) {
-                #executive::offchain_worker(header)
+                <#executive>::offchain_worker(header)
             }
         }
 
         impl frame_system_rpc_runtime_api::AccountNonceApi<#block, #account_id, #nonce> for #runtime {
             fn account_nonce(account: #account_id) -> #nonce {
-                #system::account_nonce(account)
+                <#system>::account_nonce(account)
             }
         }
 
@@ -168,7 +210,7 @@ pub fn system_apis(
             fn collect_collation_info(
                 header: &<#block as sp_runtime::traits::Block>::Header,
             ) -> cumulus_primitives_core::CollationInfo {
-                #parachain_system::collect_collation_info(header)
+                <#parachain_system>::collect_collation_info(header)
             }
         }
 
@@ -177,8 +219,8 @@ pub fn system_apis(
             fn on_runtime_upgrade(
                 checks: frame_try_runtime::UpgradeCheckSelect,
             ) -> (frame_support::weights::Weight, frame_support::weights::Weight) {
-                let weight = #executive::try_runtime_upgrade(checks).unwrap();
-                (weight, #runtime_block_weights::get().max_block)
+                let weight = <#executive>::try_runtime_upgrade(checks).unwrap();
+                (weight, <#runtime_block_weights>::get().max_block)
             }
 
             fn execute_block(
@@ -189,7 +231,7 @@ pub fn system_apis(
             ) -> frame_support::weights::Weight {
                 // NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
                 // have a backtrace here.
-                #executive::try_execute_block(block, state_root_check, signature_check, select)
+                <#executive>::try_execute_block(block, state_root_check, signature_check, select)
                     .unwrap()
             }
         }
@@ -200,12 +242,99 @@ pub fn system_apis(
             }
 
             fn get_preset(id: &Option<sp_genesis_builder::PresetId>) -> Option<sp_std::prelude::Vec<u8>> {
-                frame_support::genesis_builder_helper::get_preset::<#genesis>(id, |_| None)
+                frame_support::genesis_builder_helper::get_preset::<#genesis>(id, #get_preset)
             }
 
             fn preset_names() -> sp_std::prelude::Vec<sp_genesis_builder::PresetId> {
-                Default::default()
+                #preset_names
             }
         }
     }
 }
+
+/// Builds the `development` and `local_testnet` presets from the well-known development accounts.
+///
+/// The patch configures every pallet the abstractions may provide, the pallets missing from the default
+/// `RuntimeGenesisConfig` are removed from it before it is returned.
+fn generated_presets(
+    genesis: &Type,
+    account_id: &Type,
+    presets: &GenesisPresets,
+) -> (TokenStream, TokenStream) {
+    let dev_account_pair = match &presets.dev_account_pair {
+        Some(pair) => quote!(#pair),
+        None => quote!(sp_core::sr25519::Pair),
+    };
+    let parachain_id = match &presets.parachain_id {
+        Some(id) => quote!(#id),
+        None => quote!(1000u32),
+    };
+    let evm_chain_id = presets.evm_chain_id.as_ref().map(|id| {
+        quote! {
+            patch["evmChainId"] = serde_json::json!({ "chainId": #id });
+        }
+    });
+
+    let get_preset = quote! {
+        |id: &sp_genesis_builder::PresetId| -> Option<sp_std::prelude::Vec<u8>> {
+            let id: &[u8] = id.as_ref();
+            let (collators, endowed): (&[&str], &[&str]) = match id {
+                b"development" => (&["//Alice"], &["//Alice", "//Bob"]),
+                b"local_testnet" => (
+                    &["//Alice", "//Bob"],
+                    &["//Alice", "//Bob", "//Charlie", "//Dave", "//Eve", "//Ferdie"],
+                ),
+                _ => return None,
+            };
+            let account = |seed: &str| -> #account_id {
+                <#dev_account_pair as sp_core::Pair>::from_string(seed, None)
+                    .expect("static values are valid; qed")
+                    .public()
+                    .into()
+            };
+            let aura = |seed: &str| {
+                <sp_core::sr25519::Pair as sp_core::Pair>::from_string(seed, None)
+                    .expect("static values are valid; qed")
+                    .public()
+            };
+
+            let mut patch = serde_json::json!({
+                "balances": {
+                    "balances": endowed
+                        .iter()
+                        .map(|seed| (account(seed), 1u64 << 60))
+                        .collect::<sp_std::prelude::Vec<_>>(),
+                },
+                "parachainInfo": { "parachainId": #parachain_id },
+                "collatorSelection": {
+                    "invulnerables": collators
+                        .iter()
+                        .map(|seed| account(seed))
+                        .collect::<sp_std::prelude::Vec<_>>(),
+                },
+                "session": {
+                    "keys": collators
+                        .iter()
+                        .map(|seed| (account(seed), account(seed), serde_json::json!({ "aura": aura(seed) })))
+                        .collect::<sp_std::prelude::Vec<_>>(),
+                },
+                "sudo": { "key": account("//Alice") },
+            });
+            #evm_chain_id
+
+            // Only the pallets present in the runtime are configured
+            let default = serde_json::to_value(<#genesis as Default>::default()).ok()?;
+            if let (Some(patch), Some(default)) = (patch.as_object_mut(), default.as_object()) {
+                patch.retain(|pallet, _| default.contains_key(pallet));
+            }
+            serde_json::to_vec(&patch).ok()
+        }
+    };
+    let preset_names = quote! {
+        sp_std::vec![
+            sp_genesis_builder::PresetId::from("development"),
+            sp_genesis_builder::PresetId::from("local_testnet"),
+        ]
+    };
+    (get_preset, preset_names)
+}
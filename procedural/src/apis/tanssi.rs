@@ -1,47 +1,47 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Ident, Item};
+use syn::{Item, ItemMod, Type};
 
-use super::fetch_ident;
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
 
 #[derive(Debug)]
 pub struct TanssiAPIFields {
-    pub session_keys: Ident,
+    pub session_keys: Type,
 }
 
-impl TryFrom<&[Item]> for TanssiAPIFields {
-    type Error = &'static str;
+impl TryFrom<&ItemMod> for TanssiAPIFields {
+    type Error = syn::Error;
 
-    fn try_from(value: &[Item]) -> Result<Self, Self::Error> {
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
         let mut session_keys = None;
 
-        for item in value {
-            match item {
-                Item::Type(ty) => {
-                    let typ = ty.ty.clone();
-                    if ty.ident == "SessionKeys" {
-                        session_keys = Some(fetch_ident(&typ))
-                    }
+        for item in mod_items(value)? {
+            if let Item::Type(ty) = item {
+                if ty.ident == "SessionKeys" {
+                    session_keys = errors.collect(fetch_type(&ty.ty))
                 }
-                _ => (),
             }
         }
-        let session_keys = session_keys.ok_or("type `SessionKeys` not specified, but required")?;
-
-        return Ok(TanssiAPIFields { session_keys });
+        let session_keys = errors.require(session_keys, &value.ident, "SessionKeys");
+        let checked = errors.finish()?;
+        Ok(TanssiAPIFields {
+            session_keys: session_keys.get(&checked)?,
+        })
     }
 }
 
-pub fn tanssi_apis(runtime: &Ident, block: &Ident, session_keys: &Ident) -> TokenStream {
+pub fn tanssi_apis(runtime: &Type, block: &Type, session_keys: &Type) -> TokenStream {
     let mut res = quote! {};
     res.extend(quote! {
         impl sp_session::SessionKeys<#block> for #runtime {
             fn generate_session_keys(seed: Option<sp_std::prelude::Vec<u8>>) -> sp_std::prelude::Vec<u8> {
-                #session_keys::generate(seed)
+                <#session_keys>::generate(seed)
             }
 
             fn decode_session_keys(encoded: sp_std::prelude::Vec<u8>) -> Option<sp_std::prelude::Vec<(sp_std::prelude::Vec<u8>, sp_core::crypto::KeyTypeId)>> {
-                #session_keys::decode_into_raw_public_keys(&encoded)
+                <#session_keys>::decode_into_raw_public_keys(&encoded)
             }
         }
     });
@@ -0,0 +1,202 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Item, ItemMod, Type};
+
+use super::{fetch_type, mod_items};
+use crate::errors::Errors;
+
+#[derive(Debug)]
+pub struct XcmAPIFields {
+    pub xcm_config: Type,
+    pub xcm_router: Type,
+    pub xcm_weight_trader: Type,
+    pub call: Type,
+    pub event: Type,
+    pub origin_caller: Type,
+    pub account_id: Type,
+}
+
+impl TryFrom<&ItemMod> for XcmAPIFields {
+    type Error = syn::Error;
+
+    fn try_from(value: &ItemMod) -> Result<Self, Self::Error> {
+        let mut errors = Errors::default();
+        let mut xcm_config = None;
+        let mut xcm_router = None;
+        let mut xcm_weight_trader = None;
+        let mut call = None;
+        let mut event = None;
+        let mut origin_caller = None;
+        let mut account_id = None;
+
+        for item in mod_items(value)? {
+            if let Item::Type(ty) = item {
+                if ty.ident == "XCMConfig" {
+                    xcm_config = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "XcmRouter" {
+                    xcm_router = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "XcmWeightTrader" {
+                    xcm_weight_trader = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "RuntimeCall" {
+                    call = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "RuntimeEvent" {
+                    event = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "OriginCaller" {
+                    origin_caller = errors.collect(fetch_type(&ty.ty))
+                } else if ty.ident == "AccountId" {
+                    account_id = errors.collect(fetch_type(&ty.ty))
+                }
+            }
+        }
+
+        let xcm_config = errors.require(xcm_config, &value.ident, "XCMConfig");
+        let xcm_router = errors.require(xcm_router, &value.ident, "XcmRouter");
+        let xcm_weight_trader = errors.require(xcm_weight_trader, &value.ident, "XcmWeightTrader");
+        let call = errors.require(call, &value.ident, "RuntimeCall");
+        let event = errors.require(event, &value.ident, "RuntimeEvent");
+        let origin_caller = errors.require(origin_caller, &value.ident, "OriginCaller");
+        let account_id = errors.require(account_id, &value.ident, "AccountId");
+        let checked = errors.finish()?;
+        Ok(XcmAPIFields {
+            xcm_config: xcm_config.get(&checked)?,
+            xcm_router: xcm_router.get(&checked)?,
+            xcm_weight_trader: xcm_weight_trader.get(&checked)?,
+            call: call.get(&checked)?,
+            event: event.get(&checked)?,
+            origin_caller: origin_caller.get(&checked)?,
+            account_id: account_id.get(&checked)?,
+        })
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn xcm_apis(
+    runtime: &Type,
+    block: &Type,
+    xcm_config: &Type,
+    xcm_router: &Type,
+    xcm_weight_trader: &Type,
+    call: &Type,
+    event: &Type,
+    origin_caller: &Type,
+    account_id: &Type,
+) -> TokenStream {
+    quote! {
+        impl xcm_runtime_apis::fees::XcmPaymentApi<#block> for #runtime {
+            fn query_acceptable_payment_assets(
+                xcm_version: xcm::Version,
+            ) -> Result<sp_std::prelude::Vec<xcm::VersionedAssetId>, xcm_runtime_apis::fees::Error> {
+                // The native asset and the assets registered as supported in the weight trader
+                <#xcm_weight_trader>::query_acceptable_payment_assets(xcm_version)
+            }
+
+            fn query_weight_to_asset_fee(
+                weight: frame_support::weights::Weight,
+                asset: xcm::VersionedAssetId,
+            ) -> Result<u128, xcm_runtime_apis::fees::Error> {
+                // Priced with the relative price of the asset registered in the weight trader
+                <#xcm_weight_trader>::query_weight_to_asset_fee(weight, asset)
+            }
+
+            fn query_xcm_weight(
+                message: xcm::VersionedXcm<()>,
+            ) -> Result<frame_support::weights::Weight, xcm_runtime_apis::fees::Error> {
+                let message = xcm::latest::Xcm::<()>::try_from(message)
+                    .map_err(|_| xcm_runtime_apis::fees::Error::VersionedConversionFailed)?;
+                <<#xcm_config as xcm_executor::Config>::Weigher as xcm_executor::traits::WeightBounds<
+                    <#xcm_config as xcm_executor::Config>::RuntimeCall,
+                >>::weight(&mut message.into())
+                .map_err(|_| xcm_runtime_apis::fees::Error::WeightNotComputable)
+            }
+
+            fn query_delivery_fees(
+                destination: xcm::VersionedLocation,
+                message: xcm::VersionedXcm<()>,
+            ) -> Result<xcm::VersionedAssets, xcm_runtime_apis::fees::Error> {
+                use xcm::IdentifyVersion;
+                // The fees are reported in the newest version of the request
+                let version = destination.identify_version().max(message.identify_version());
+                let destination = xcm::latest::Location::try_from(destination)
+                    .map_err(|_| xcm_runtime_apis::fees::Error::VersionedConversionFailed)?;
+                let message = xcm::latest::Xcm::<()>::try_from(message)
+                    .map_err(|_| xcm_runtime_apis::fees::Error::VersionedConversionFailed)?;
+                let (_, fees) = xcm::latest::validate_send::<#xcm_router>(destination, message)
+                    .map_err(|_| xcm_runtime_apis::fees::Error::Unroutable)?;
+                xcm::VersionedAssets::from(fees)
+                    .into_version(version)
+                    .map_err(|_| xcm_runtime_apis::fees::Error::VersionedConversionFailed)
+            }
+        }
+
+        impl xcm_runtime_apis::dry_run::DryRunApi<#block, #call, #event, #origin_caller> for #runtime {
+            fn dry_run_call(
+                origin: #origin_caller,
+                call: #call,
+            ) -> Result<xcm_runtime_apis::dry_run::CallDryRunEffects<#event>, xcm_runtime_apis::dry_run::Error> {
+                use xcm_builder::InspectMessageQueues;
+                use xcm_executor::RecordXcm;
+
+                pallet_xcm::Pallet::<#runtime>::set_record_xcm(true);
+                // Only the events of the dry-run call are reported
+                frame_system::Pallet::<#runtime>::reset_events();
+                let result = <#call as sp_runtime::traits::Dispatchable>::dispatch(call, origin.into());
+                pallet_xcm::Pallet::<#runtime>::set_record_xcm(false);
+                let local_xcm = pallet_xcm::Pallet::<#runtime>::recorded_xcm();
+                let forwarded_xcms = <#xcm_router>::get_messages();
+                let events = frame_system::Pallet::<#runtime>::read_events_no_consensus()
+                    .map(|record| record.event.clone())
+                    .collect();
+                Ok(xcm_runtime_apis::dry_run::CallDryRunEffects {
+                    local_xcm: local_xcm.map(xcm::VersionedXcm::<()>::from),
+                    forwarded_xcms,
+                    emitted_events: events,
+                    execution_result: result,
+                })
+            }
+
+            fn dry_run_xcm(
+                origin_location: xcm::VersionedLocation,
+                xcm: xcm::VersionedXcm<#call>,
+            ) -> Result<xcm_runtime_apis::dry_run::XcmDryRunEffects<#event>, xcm_runtime_apis::dry_run::Error> {
+                use frame_support::pallet_prelude::Encode;
+                use xcm_builder::InspectMessageQueues;
+
+                let origin_location = xcm::latest::Location::try_from(origin_location)
+                    .map_err(|_| xcm_runtime_apis::dry_run::Error::VersionedConversionFailed)?;
+                let xcm = xcm::latest::Xcm::<#call>::try_from(xcm)
+                    .map_err(|_| xcm_runtime_apis::dry_run::Error::VersionedConversionFailed)?;
+                let mut hash = xcm.using_encoded(sp_core::hashing::blake2_256);
+                // Only the events of the dry-run message are reported
+                frame_system::Pallet::<#runtime>::reset_events();
+                let result = xcm_executor::XcmExecutor::<#xcm_config>::prepare_and_execute(
+                    origin_location,
+                    xcm,
+                    &mut hash,
+                    frame_support::weights::Weight::MAX,
+                    frame_support::weights::Weight::zero(),
+                );
+                let forwarded_xcms = <#xcm_router>::get_messages();
+                let events = frame_system::Pallet::<#runtime>::read_events_no_consensus()
+                    .map(|record| record.event.clone())
+                    .collect();
+                Ok(xcm_runtime_apis::dry_run::XcmDryRunEffects {
+                    forwarded_xcms,
+                    emitted_events: events,
+                    execution_result: result,
+                })
+            }
+        }
+
+        impl xcm_runtime_apis::conversions::LocationToAccountApi<#block, #account_id> for #runtime {
+            fn convert_location(
+                location: xcm::VersionedLocation,
+            ) -> Result<#account_id, xcm_runtime_apis::conversions::Error> {
+                // Accounts are derived the same way as the sovereign accounts of `pallet_xcm`
+                xcm_runtime_apis::conversions::LocationToAccountHelper::<
+                    #account_id,
+                    <#runtime as pallet_xcm::Config>::SovereignAccountOf,
+                >::convert_location(location)
+            }
+        }
+    }
+}
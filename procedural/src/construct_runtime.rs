@@ -1,48 +1,175 @@
-use crate::models::ConstructAbstractions;
+use crate::{
+    apis::mod_items, errors::Errors, models::ConstructAbstractions, pallet_indices,
+    runtime_types::construct_types,
+};
+use darling::{ast::NestedMeta, util::PathList, FromMeta};
 use proc_macro::TokenStream;
 use proc_macro2::{Literal, Span};
-use quote::quote;
-use syn::{parse_macro_input, Ident, Item, ItemStruct, ItemType, Type};
+use quote::{quote, ToTokens};
+use std::collections::BTreeMap;
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, Attribute, Ident, Item, ItemMod,
+    ItemStruct, ItemType, LitInt, LitStr, Path, Token, Type,
+};
 
-pub fn construct_openzeppelin_runtime(tokens: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(tokens as syn::ItemMod);
-    let Some((_, items)) = input.content else {
-        panic!("no content");
-    };
+/// Arguments of the `openzeppelin_construct_runtime` attribute.
+#[derive(Default, FromMeta)]
+#[darling(default)]
+struct RuntimeArgs {
+    /// Path to the pallet index manifest, relative to the runtime crate.
+    pallet_indices: Option<LitStr>,
+    /// Changes to the aggregates derived by the runtime.
+    derive: DeriveArgs,
+}
+
+/// `derive(add(..), remove(..))` argument, adjusting the default `runtime::derive` list.
+#[derive(Default, FromMeta)]
+#[darling(default)]
+struct DeriveArgs {
+    add: PathList,
+    remove: PathList,
+}
+
+/// Aggregates derived by the runtime unless removed.
+const DEFAULT_DERIVES: [&str; 9] = [
+    "RuntimeCall",
+    "RuntimeEvent",
+    "RuntimeError",
+    "RuntimeOrigin",
+    "RuntimeFreezeReason",
+    "RuntimeHoldReason",
+    "RuntimeSlashReason",
+    "RuntimeLockId",
+    "RuntimeTask",
+];
+
+impl DeriveArgs {
+    /// Returns the aggregates to derive, validating the requested changes.
+    fn derives(&self) -> syn::Result<Vec<Path>> {
+        let mut errors = Errors::default();
+        let is_default = |path: &Path| DEFAULT_DERIVES.iter().any(|name| path.is_ident(name));
+        for path in self.remove.iter().filter(|path| !is_default(path)) {
+            errors.push(syn::Error::new_spanned(
+                path,
+                format!(
+                    "cannot remove an aggregate that is not derived by default, expected one of: {}",
+                    DEFAULT_DERIVES.join(", ")
+                ),
+            ));
+        }
+        for path in self.add.iter().filter(|path| is_default(path)) {
+            errors.push(syn::Error::new_spanned(
+                path,
+                "aggregate is already derived by default",
+            ));
+        }
+        errors.finish()?;
+
+        let mut derives: Vec<Path> = DEFAULT_DERIVES
+            .iter()
+            .filter(|name| !self.remove.iter().any(|path| path.is_ident(name)))
+            .map(|name| construct_ident(name).into())
+            .collect();
+        derives.extend(self.add.iter().cloned());
+        Ok(derives)
+    }
+}
+
+pub fn construct_openzeppelin_runtime(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as ItemMod);
+    construct_runtime(args.into(), &input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+pub fn construct_runtime(
+    args: proc_macro2::TokenStream,
+    input: &ItemMod,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let args = RuntimeArgs::from_list(&NestedMeta::parse_meta_list(args)?)?;
+    let derives = args.derive.derives()?;
+    let mut errors = Errors::default();
     let mut inner = quote! {};
     let mut outer = quote! {};
-    let mut pallet_index = 0;
+    let mut pallet_index = PalletIndex::default();
+    let mut abstractions = vec![];
+    let mut types = None;
+    let mut composition = vec![];
 
-    for item in items {
+    for item in mod_items(input)? {
         match item {
+            Item::Mod(m) if m.ident == "types" => types = Some(m),
             Item::Struct(m) => {
-                let (abstraction, append) = parse_abstraction(m, &mut pallet_index);
-                inner.extend(abstraction);
-                outer.extend(append);
+                let Some(abstraction_name) = errors.collect(ConstructAbstractions::try_from(m))
+                else {
+                    continue;
+                };
+                abstractions.push((abstraction_name, m));
+                if let Some((abstraction, append, info)) =
+                    errors.collect(parse_abstraction(m, abstraction_name, &mut pallet_index))
+                {
+                    inner.extend(abstraction);
+                    outer.extend(append);
+                    composition.push(info);
+                }
             }
             Item::Type(item) => {
-                let pallet = parse_pallet(item, &mut pallet_index);
-                inner.extend(pallet);
+                if let Some(pallet) = errors.collect(parse_pallet(item, &mut pallet_index)) {
+                    inner.extend(pallet);
+                }
+            }
+            // Items unrelated to pallets are passed through unchanged
+            other => outer.extend(other.to_token_stream()),
+        }
+    }
+
+    for (abstraction, item) in abstractions.iter() {
+        for dependency in abstraction.dependencies() {
+            match abstractions
+                .iter()
+                .find(|(present, _)| present == dependency)
+            {
+                None => errors.push(syn::Error::new_spanned(
+                    &item.ident,
+                    format!("{abstraction} requires {dependency}, add `struct {dependency};` to the runtime"),
+                )),
+                // The dependency has to be present in every configuration the abstraction is enabled in
+                Some((_, present)) if !cfgs_cover(item, present) => {
+                    errors.push(syn::Error::new_spanned(
+                        &item.ident,
+                        format!("{abstraction} requires {dependency}, which has `#[cfg]` attributes that {abstraction} does not have, gate them the same way"),
+                    ))
+                }
+                Some(_) => (),
             }
-            _ => (),
         }
     }
+    if let Some(types) = types {
+        let evm = abstractions
+            .iter()
+            .any(|(abstraction, _)| *abstraction == ConstructAbstractions::Evm);
+        if let Some(types) = errors.collect(construct_types(types, evm)) {
+            outer.extend(types);
+        }
+    }
+    errors.finish()?;
+
+    if let Some(path) = &args.pallet_indices {
+        outer.extend(pallet_indices::check(path, &pallet_index.used)?);
+    }
 
+    outer.extend(quote! {
+        /// OpenZeppelin abstractions the runtime is composed of.
+        pub const RUNTIME_COMPOSITION: &[openzeppelin_pallet_abstractions::runtime_api::AbstractionInfo] = &[#(#composition),*];
+    });
+
+    let attrs = &input.attrs;
     let expanded = quote! {
+        #(#attrs)*
         #[frame_support::runtime]
         mod runtime {
             #[runtime::runtime]
-            #[runtime::derive(
-                RuntimeCall,
-                RuntimeEvent,
-                RuntimeError,
-                RuntimeOrigin,
-                RuntimeFreezeReason,
-                RuntimeHoldReason,
-                RuntimeSlashReason,
-                RuntimeLockId,
-                RuntimeTask
-            )]
+            #[runtime::derive(#(#derives),*)]
             pub struct Runtime;
 
             #inner
@@ -50,128 +177,351 @@ pub fn construct_openzeppelin_runtime(tokens: TokenStream) -> TokenStream {
 
         #outer
     };
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
 
+/// Checks that every `cfg` attribute of `dependency` is also an attribute of `item`, so that `dependency` is not
+/// disabled while `item` is enabled.
+fn cfgs_cover(item: &ItemStruct, dependency: &ItemStruct) -> bool {
+    let cfgs = |item: &ItemStruct| -> Vec<String> {
+        item.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .map(|attr| attr.to_token_stream().to_string())
+            .collect()
+    };
+    let item_cfgs = cfgs(item);
+    cfgs(dependency).iter().all(|cfg| item_cfgs.contains(cfg))
+}
+
+/// Returns the pallet declarations of the abstraction, the code generated outside of the runtime, if any,
+/// and the `AbstractionInfo` of the abstraction.
 fn parse_abstraction(
-    item: ItemStruct,
-    index: &mut u32,
-) -> (proc_macro2::TokenStream, Option<proc_macro2::TokenStream>) {
-    let abstraction_name = ConstructAbstractions::try_from(item).expect("Wrong Struct");
-
-    match abstraction_name {
-        ConstructAbstractions::System => (construct_system(index), None),
-        ConstructAbstractions::Assets => (construct_assets(index), None),
-        ConstructAbstractions::Consensus => (
-            construct_consensus(index),
-            Some(quote! {
-                cumulus_pallet_parachain_system::register_validate_block! {
-                    Runtime = Runtime,
-                    BlockExecutor = cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>,
+    item: &ItemStruct,
+    abstraction_name: ConstructAbstractions,
+    index: &mut PalletIndex,
+) -> syn::Result<(
+    proc_macro2::TokenStream,
+    Option<proc_macro2::TokenStream>,
+    proc_macro2::TokenStream,
+)> {
+    index.pin(&item.attrs)?;
+    // `cfg` of the abstraction applies to the code generated outside of the runtime as well
+    let cfgs: Vec<_> = item
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect();
+    let validate_block = ValidateBlock::parse(&item.attrs)?;
+    if let Some(attr) = &validate_block.attr {
+        if !matches!(
+            abstraction_name,
+            ConstructAbstractions::Consensus | ConstructAbstractions::Tanssi
+        ) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`validate_block` is supported only by Consensus and Tanssi abstractions",
+            ));
+        }
+    }
+
+    let (pallets, info) = match abstraction_name {
+        ConstructAbstractions::System => construct_system(index, item)?,
+        ConstructAbstractions::Assets => construct_assets(index, item)?,
+        ConstructAbstractions::Consensus => construct_consensus(index, item)?,
+        ConstructAbstractions::Governance => construct_governance(index, item)?,
+        ConstructAbstractions::Xcm => construct_xcm(index, item)?,
+        ConstructAbstractions::Evm => construct_evm(index, item)?,
+        ConstructAbstractions::Tanssi => construct_tanssi(index, item)?,
+    };
+
+    let outer = match abstraction_name {
+        ConstructAbstractions::Consensus => {
+            let executor = validate_block.executor.unwrap_or_else(|| {
+                parse_quote!(cumulus_pallet_aura_ext::BlockExecutor::<Runtime, Executive>)
+            });
+            (!validate_block.skip).then(|| {
+                quote! {
+                    #(#cfgs)*
+                    cumulus_pallet_parachain_system::register_validate_block! {
+                        Runtime = Runtime,
+                        BlockExecutor = #executor,
+                    }
                 }
-            }),
-        ),
-        ConstructAbstractions::Governance => (construct_governance(index), None),
-        ConstructAbstractions::Xcm => (construct_xcm(index), None),
-        ConstructAbstractions::Evm => (construct_evm(index), None),
-        ConstructAbstractions::Tanssi => (
-            construct_tanssi(index),
-            Some(quote! {
-                #[allow(dead_code)]
-                struct CheckInherents;
-
-                #[allow(deprecated)]
-                impl cumulus_pallet_parachain_system::CheckInherents<Block> for CheckInherents {
-                    fn check_inherents(
-                        block: &Block,
-                        relay_state_proof: &cumulus_pallet_parachain_system::RelayChainStateProof,
-                    ) -> sp_inherents::CheckInherentsResult {
-                        let relay_chain_slot = relay_state_proof
-                            .read_slot()
-                            .expect("Could not read the relay chain slot from the proof");
-
-                        let inherent_data =
-                            cumulus_primitives_timestamp::InherentDataProvider::from_relay_chain_slot_and_duration(
-                                relay_chain_slot,
-                                sp_std::time::Duration::from_secs(6),
-                            )
-                            .create_inherent_data()
-                            .expect("Could not create the timestamp inherent data");
-
-                        inherent_data.check_extrinsics(block)
+            })
+        }
+        ConstructAbstractions::Tanssi => {
+            let executor = validate_block.executor.unwrap_or_else(|| {
+                parse_quote!(pallet_author_inherent::BlockExecutor::<Runtime, Executive>)
+            });
+            (!validate_block.skip).then(|| {
+                quote! {
+                    #(#cfgs)*
+                    #[allow(dead_code)]
+                    struct CheckInherents;
+
+                    #(#cfgs)*
+                    #[allow(deprecated)]
+                    impl cumulus_pallet_parachain_system::CheckInherents<Block> for CheckInherents {
+                        fn check_inherents(
+                            block: &Block,
+                            relay_state_proof: &cumulus_pallet_parachain_system::RelayChainStateProof,
+                        ) -> sp_inherents::CheckInherentsResult {
+                            let relay_chain_slot = relay_state_proof
+                                .read_slot()
+                                .expect("Could not read the relay chain slot from the proof");
+
+                            // The slot duration, in milliseconds, configured by `SystemConfig::SlotDuration`
+                            let slot_duration = <RuntimeSlotDuration as frame_support::traits::Get<u64>>::get();
+                            let inherent_data =
+                                cumulus_primitives_timestamp::InherentDataProvider::from_relay_chain_slot_and_duration(
+                                    relay_chain_slot,
+                                    sp_std::time::Duration::from_millis(slot_duration),
+                                )
+                                .create_inherent_data()
+                                .expect("Could not create the timestamp inherent data");
+
+                            inherent_data.check_extrinsics(block)
+                        }
+                    }
+
+                    #(#cfgs)*
+                    cumulus_pallet_parachain_system::register_validate_block! {
+                        Runtime = Runtime,
+                        BlockExecutor = #executor,
+                        CheckInherents = CheckInherents
                     }
                 }
+            })
+        }
+        ConstructAbstractions::System
+        | ConstructAbstractions::Assets
+        | ConstructAbstractions::Governance
+        | ConstructAbstractions::Xcm
+        | ConstructAbstractions::Evm => None,
+    };
+    // A disabled abstraction is left out of `RUNTIME_COMPOSITION`
+    let info = quote! {
+        #(#cfgs)*
+        #info
+    };
+    Ok((pallets, outer, info))
+}
 
-                cumulus_pallet_parachain_system::register_validate_block! {
-                    Runtime = Runtime,
-                    BlockExecutor = pallet_author_inherent::BlockExecutor::<Runtime, Executive>,
-                    CheckInherents = CheckInherents
+/// Options of the `#[validate_block(..)]` attribute of the Consensus and Tanssi abstractions.
+#[derive(Default)]
+struct ValidateBlock<'a> {
+    /// The attribute itself, if present.
+    attr: Option<&'a Attribute>,
+    /// Do not register `validate_block`, the runtime registers it itself.
+    skip: bool,
+    /// Block executor passed to `register_validate_block!`.
+    executor: Option<Type>,
+}
+
+impl<'a> ValidateBlock<'a> {
+    fn parse(attrs: &'a [Attribute]) -> syn::Result<Self> {
+        let mut res = ValidateBlock::default();
+        for attr in attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("validate_block"))
+        {
+            res.attr = Some(attr);
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    res.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("executor") {
+                    res.executor = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported option, expected `skip` or `executor = Type`"))
                 }
-            }),
-        ),
+            })?;
+        }
+        if let (true, Some(executor)) = (res.skip, &res.executor) {
+            return Err(syn::Error::new_spanned(
+                executor,
+                "`executor` has no effect when `validate_block` registration is skipped",
+            ));
+        }
+        Ok(res)
     }
 }
 
-fn construct_xcm(index: &mut u32) -> proc_macro2::TokenStream {
-    construct_abstraction(index, &openzeppelin_pallet_abstractions::xcm::PALLET_NAMES)
+fn construct_xcm(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    construct_abstraction(
+        index,
+        item,
+        &openzeppelin_pallet_abstractions::xcm::PALLET_NAMES,
+    )
 }
 
-fn construct_tanssi(index: &mut u32) -> proc_macro2::TokenStream {
+fn construct_tanssi(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     construct_abstraction(
         index,
+        item,
         &openzeppelin_pallet_abstractions::tanssi::PALLET_NAMES,
     )
 }
 
-fn construct_governance(index: &mut u32) -> proc_macro2::TokenStream {
+fn construct_governance(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     construct_abstraction(
         index,
+        item,
         &openzeppelin_pallet_abstractions::governance::PALLET_NAMES,
     )
 }
 
-fn construct_consensus(index: &mut u32) -> proc_macro2::TokenStream {
+fn construct_consensus(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     construct_abstraction(
         index,
+        item,
         &openzeppelin_pallet_abstractions::consensus::PALLET_NAMES,
     )
 }
 
-fn construct_evm(index: &mut u32) -> proc_macro2::TokenStream {
-    construct_abstraction(index, &openzeppelin_pallet_abstractions::evm::PALLET_NAMES)
+fn construct_evm(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    construct_abstraction(
+        index,
+        item,
+        &openzeppelin_pallet_abstractions::evm::PALLET_NAMES,
+    )
 }
 
-fn construct_assets(index: &mut u32) -> proc_macro2::TokenStream {
+fn construct_assets(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     construct_abstraction(
         index,
+        item,
         &openzeppelin_pallet_abstractions::assets::PALLET_NAMES,
     )
 }
 
-fn construct_system(index: &mut u32) -> proc_macro2::TokenStream {
+fn construct_system(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
     construct_abstraction(
         index,
+        item,
         &openzeppelin_pallet_abstractions::system::PALLET_NAMES,
     )
 }
 
-fn construct_abstraction(index: &mut u32, pallets: &[(&str, &str)]) -> proc_macro2::TokenStream {
+/// Returns the pallet declarations of the abstraction and its `AbstractionInfo` for `RUNTIME_COMPOSITION`.
+fn construct_abstraction(
+    index: &mut PalletIndex,
+    item: &ItemStruct,
+    pallets: &[(&str, &str)],
+) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let mut errors = Errors::default();
+    let excluded = parse_exclusions(&item.attrs)?;
+    check_exclusions(&mut errors, item, &excluded, pallets);
+
+    let attrs = forwarded_attrs(&item.attrs);
     let mut res = quote! {};
+    let mut composition = vec![];
     for (name, module) in pallets {
-        res.extend(construct_pallet(
-            index,
-            construct_ident(name),
-            construct_ident(module),
-        ));
+        let name = construct_ident(name);
+        if excluded.iter().any(|excluded| excluded == &name) {
+            index.skip();
+            continue;
+        }
+        if let Some(index_literal) = errors.collect(index.assign(&name, &item.ident)) {
+            let pallet_name = name.to_string();
+            composition.push(quote! {
+                openzeppelin_pallet_abstractions::runtime_api::PalletComposition::new(#pallet_name, #index_literal)
+            });
+            res.extend(construct_pallet(
+                &attrs,
+                index_literal,
+                name,
+                construct_ident(module),
+            ));
+        }
+    }
+    errors.finish()?;
+
+    let abstraction_name = item.ident.to_string();
+    let info = quote! {
+        {
+            const PALLETS: &[openzeppelin_pallet_abstractions::runtime_api::PalletComposition] = &[#(#composition),*];
+            openzeppelin_pallet_abstractions::runtime_api::AbstractionInfo::new(#abstraction_name, PALLETS)
+        }
+    };
+    Ok((res, info))
+}
+
+/// Parses the pallets listed in `#[exclude(..)]` attributes.
+pub fn parse_exclusions(attrs: &[Attribute]) -> syn::Result<Vec<Ident>> {
+    let mut excluded = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("exclude")) {
+        excluded.extend(attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?);
+    }
+    Ok(excluded)
+}
+
+/// Reports the excluded names that are not pallets of the abstraction.
+pub fn check_exclusions(
+    errors: &mut Errors,
+    item: &ItemStruct,
+    excluded: &[Ident],
+    pallets: &[(&str, &str)],
+) {
+    for name in excluded.iter() {
+        if !pallets.iter().any(|(pallet, _)| name == pallet) {
+            let available: Vec<_> = pallets.iter().map(|(pallet, _)| *pallet).collect();
+            errors.push(syn::Error::new_spanned(
+                name,
+                format!(
+                    "`{name}` is not a pallet of `{}` abstraction, expected one of: {}",
+                    item.ident,
+                    available.join(", ")
+                ),
+            ));
+        }
     }
-    res
 }
 
-fn construct_pallet(index: &mut u32, name: Ident, ty: Ident) -> proc_macro2::TokenStream {
-    let index_literal = Literal::u32_unsuffixed(*index);
-    *index += 1;
+/// Attributes that are not consumed by this macro, such as `cfg` or doc comments.
+fn forwarded_attrs(attrs: &[Attribute]) -> Vec<&Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| {
+            !["pallet", "index", "exclude", "validate_block"]
+                .iter()
+                .any(|consumed| attr.path().is_ident(consumed))
+        })
+        .collect()
+}
+
+fn construct_pallet(
+    attrs: &[&Attribute],
+    index: Literal,
+    name: Ident,
+    ty: impl ToTokens,
+) -> proc_macro2::TokenStream {
     quote! {
-        #[runtime::pallet_index(#index_literal)]
+        #(#attrs)*
+        #[runtime::pallet_index(#index)]
         pub type #name = #ty;
     }
 }
@@ -180,7 +530,7 @@ fn construct_ident(name: &str) -> syn::Ident {
     Ident::new(name, Span::call_site())
 }
 
-fn parse_pallet(item: ItemType, index: &mut u32) -> proc_macro2::TokenStream {
+fn parse_pallet(item: &ItemType, index: &mut PalletIndex) -> syn::Result<proc_macro2::TokenStream> {
     let is_pallet = item.attrs.iter().any(|f| {
         let Ok(path) = f.meta.require_path_only() else {
             return false;
@@ -191,23 +541,199 @@ fn parse_pallet(item: ItemType, index: &mut u32) -> proc_macro2::TokenStream {
         ident == "pallet"
     });
     if !is_pallet {
-        panic!("`pallet` attribute is missing");
+        return Err(syn::Error::new_spanned(
+            item,
+            "`pallet` attribute is missing",
+        ));
     }
     let name = &item.ident;
 
-    let ty = match *item.ty {
-        Type::Path(path) => {
-            let Some(ident) = path.path.get_ident() else {
-                panic!("Malformed type, found: {:?}", path.path);
-            };
-            ident.clone()
+    // Paths are passed through as is, so that instances and generics reach `frame_support::runtime`
+    let ty = match &*item.ty {
+        Type::Path(path) if path.qself.is_none() => path,
+        ty => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "malformed pallet type, expected a path to the pallet",
+            ))
         }
-        _ => panic!("Malformed type, found {:?}", item.ty),
     };
-    let index_literal = Literal::u32_unsuffixed(*index);
-    *index += 1;
-    quote! {
-        #[runtime::pallet_index(#index_literal)]
-        pub type #name = #ty;
+    index.pin(&item.attrs)?;
+    let index_literal = index.assign(name, name)?;
+    Ok(construct_pallet(
+        &forwarded_attrs(&item.attrs),
+        index_literal,
+        name.clone(),
+        ty,
+    ))
+}
+
+/// Running pallet index counter.
+///
+/// Indices are assigned in ascending order, starting from the one pinned by the `#[index(N)]`
+/// attribute, if any. Every assigned index is tracked to report collisions.
+#[derive(Default)]
+struct PalletIndex {
+    next: u32,
+    used: BTreeMap<u32, Ident>,
+}
+
+impl PalletIndex {
+    /// Moves the counter to the index from `#[index(N)]` attribute, if it is present.
+    fn pin(&mut self, attrs: &[Attribute]) -> syn::Result<()> {
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("index")) {
+            let index: u8 = attr.parse_args::<LitInt>()?.base10_parse()?;
+            self.next = index.into();
+        }
+        Ok(())
+    }
+
+    /// Skips the next index, leaving it free for other pallets.
+    fn skip(&mut self) {
+        self.next += 1;
+    }
+
+    /// Assigns the next index to the pallet `name`. Errors are spanned at `tokens`.
+    fn assign(&mut self, name: &Ident, tokens: impl quote::ToTokens) -> syn::Result<Literal> {
+        let index = self.next;
+        self.next += 1;
+        if index > u8::MAX.into() {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                format!("index {index} of pallet `{name}` exceeds the maximum pallet index 255"),
+            ));
+        }
+        if let Some(used_by) = self.used.insert(index, name.clone()) {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                format!("index {index} of pallet `{name}` is already used by pallet `{used_by}`"),
+            ));
+        }
+        Ok(Literal::u32_unsuffixed(index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn derive_args(add: &[Path], remove: &[Path]) -> DeriveArgs {
+        DeriveArgs {
+            add: PathList::new(add.to_vec()),
+            remove: PathList::new(remove.to_vec()),
+        }
+    }
+
+    fn names(paths: &[Path]) -> Vec<String> {
+        paths.iter().map(|path| quote!(#path).to_string()).collect()
+    }
+
+    #[test]
+    fn derives_defaults() {
+        let derives = DeriveArgs::default().derives().unwrap();
+        assert_eq!(names(&derives), DEFAULT_DERIVES);
+    }
+
+    #[test]
+    fn adds_and_removes_derives() {
+        let derives = derive_args(
+            &[parse_quote!(RuntimeViewFunction)],
+            &[parse_quote!(RuntimeTask)],
+        )
+        .derives()
+        .unwrap();
+        let mut expected: Vec<_> = DEFAULT_DERIVES[..8]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        expected.push("RuntimeViewFunction".into());
+        assert_eq!(names(&derives), expected);
+    }
+
+    #[test]
+    fn rejects_invalid_derive_changes() {
+        let errors: Vec<_> = derive_args(
+            &[parse_quote!(RuntimeCall)],
+            &[parse_quote!(RuntimeViewFunction)],
+        )
+        .derives()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect();
+        assert_eq!(
+            errors,
+            [
+                format!(
+                    "cannot remove an aggregate that is not derived by default, expected one of: {}",
+                    DEFAULT_DERIVES.join(", ")
+                ),
+                "aggregate is already derived by default".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn assigns_ascending_indices() {
+        let mut index = PalletIndex::default();
+        let first = index.assign(&format_ident!("System"), quote! {}).unwrap();
+        index.skip();
+        let second = index
+            .assign(&format_ident!("Timestamp"), quote! {})
+            .unwrap();
+        assert_eq!(first.to_string(), "0");
+        assert_eq!(second.to_string(), "2");
+    }
+
+    #[test]
+    fn pins_index() {
+        let mut index = PalletIndex::default();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[index(10)])];
+        index.pin(&attrs).unwrap();
+        let pinned = index.assign(&format_ident!("Balances"), quote! {}).unwrap();
+        let next = index.assign(&format_ident!("Vesting"), quote! {}).unwrap();
+        assert_eq!(pinned.to_string(), "10");
+        assert_eq!(next.to_string(), "11");
+    }
+
+    #[test]
+    fn rejects_invalid_pin() {
+        let mut index = PalletIndex::default();
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[index(256)])];
+        assert!(index.pin(&attrs).is_err());
+    }
+
+    #[test]
+    fn reports_collision() {
+        let mut index = PalletIndex::default();
+        index.assign(&format_ident!("System"), quote! {}).unwrap();
+        index
+            .assign(&format_ident!("Timestamp"), quote! {})
+            .unwrap();
+        index.pin(&[parse_quote!(#[index(1)])]).unwrap();
+        let error = index
+            .assign(&format_ident!("Balances"), quote! {})
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "index 1 of pallet `Balances` is already used by pallet `Timestamp`"
+        );
+    }
+
+    #[test]
+    fn reports_index_overflow() {
+        let mut index = PalletIndex::default();
+        index.pin(&[parse_quote!(#[index(255)])]).unwrap();
+        index.assign(&format_ident!("Sudo"), quote! {}).unwrap();
+        let error = index
+            .assign(&format_ident!("Utility"), quote! {})
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "index 256 of pallet `Utility` exceeds the maximum pallet index 255"
+        );
     }
 }
@@ -0,0 +1,113 @@
+use quote::ToTokens;
+
+/// Accumulates errors, so that all of them are reported in one compilation pass.
+#[derive(Default)]
+pub struct Errors(Option<syn::Error>);
+
+/// Value checked by `Errors::require`, available once `Errors::finish` reported no error.
+///
+/// A missing value keeps the error reported for it, so it is returned again rather than unlocked by the `Checked` of
+/// another accumulator.
+pub struct Required<T>(syn::Result<T>);
+
+/// Returned by `Errors::finish` when no error was reported, gives access to the `Required` values.
+pub struct Checked(());
+
+impl Errors {
+    pub fn push(&mut self, error: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(error),
+            None => self.0 = Some(error),
+        }
+    }
+
+    /// Stores the error, if any, and returns the successful value.
+    pub fn collect<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        result.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Stores the error, if any, the successful value is available once `Errors::finish` reported no error.
+    pub fn require_ok<T>(&mut self, result: syn::Result<T>) -> Required<T> {
+        if let Err(error) = &result {
+            self.push(error.clone());
+        }
+        Required(result)
+    }
+
+    /// Reports a missing `type #name` in the module identified by `tokens`.
+    pub fn require<T>(
+        &mut self,
+        value: Option<T>,
+        tokens: impl ToTokens,
+        name: &str,
+    ) -> Required<T> {
+        self.expect(value, || {
+            syn::Error::new_spanned(tokens, format!("`type {name}` not specified, but required"))
+        })
+    }
+
+    /// Reports the error built by `error` if the value is missing.
+    pub fn expect<T>(
+        &mut self,
+        value: Option<T>,
+        error: impl FnOnce() -> syn::Error,
+    ) -> Required<T> {
+        Required(value.ok_or_else(|| {
+            let error = error();
+            self.push(error.clone());
+            error
+        }))
+    }
+
+    pub fn finish(self) -> syn::Result<Checked> {
+        match self.0 {
+            Some(error) => Err(error),
+            None => Ok(Checked(())),
+        }
+    }
+}
+
+impl<T> Required<T> {
+    /// Returns the value, or the error reported for the missing value if `checked` comes from another accumulator.
+    pub fn get(self, _: &Checked) -> syn::Result<T> {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::Span;
+
+    use super::*;
+
+    #[test]
+    fn reports_missing_value_of_other_accumulator() {
+        let mut errors = Errors::default();
+        let missing: Required<u8> = errors.require(None, quote::quote! {}, "Runtime");
+        let checked = Errors::default().finish().unwrap();
+        assert_eq!(
+            missing.get(&checked).unwrap_err().to_string(),
+            "`type Runtime` not specified, but required"
+        );
+        assert!(errors.finish().is_err());
+    }
+
+    #[test]
+    fn returns_required_values() {
+        let mut errors = Errors::default();
+        let present = errors.require(Some(1), quote::quote! {}, "Runtime");
+        let collected = errors.require_ok(Ok(2));
+        let checked = errors.finish().unwrap();
+        assert_eq!(present.get(&checked).unwrap(), 1);
+        assert_eq!(collected.get(&checked).unwrap(), 2);
+    }
+
+    #[test]
+    fn reports_failed_results() {
+        let mut errors = Errors::default();
+        let failed: Required<u8> =
+            errors.require_ok(Err(syn::Error::new(Span::call_site(), "invalid")));
+        assert_eq!(errors.finish().err().unwrap().to_string(), "invalid");
+        assert!(failed.get(&Errors::default().finish().unwrap()).is_err());
+    }
+}
@@ -2,24 +2,125 @@ use proc_macro::TokenStream;
 
 mod apis;
 mod construct_runtime;
+mod errors;
 mod models;
+mod pallet_indices;
+mod runtime;
 mod runtime_apis;
+mod runtime_types;
 
 /// Construct runtime macro abstraction
 /// This macro allows to construct runtime operating with abstractions as long as with pallets.
 /// It allows you omit the pallet indices such that they are assigned in ascending order.
+/// Items other than abstractions and pallets are emitted unchanged.
 /// This API may change in future releases, it is not yet stabilised.
 ///
+/// # Pallet indices
+/// The index can be pinned with `#[index(N)]`: on an abstraction it sets the index of its first pallet,
+/// on a pallet it sets its own index. The following pallets continue counting from the pinned index.
+/// Assigning the same index twice is a compile error.
+/// ```ignore
+/// #[index(30)]
+/// struct XCM; // XCM pallets take indices starting from 30
+/// ```
+///
+/// # Exclusions
+/// Pallets can be removed from an abstraction with `#[exclude(..)]`. Excluded pallets keep their index
+/// free, so a replacement pallet can be pinned to it. The matching `impl_openzeppelin_*!` macro must be
+/// called with the same exclusions, e.g. `impl_openzeppelin_governance!(Config, exclude(Sudo))`.
+/// ```ignore
+/// #[exclude(Sudo)]
+/// struct Governance;
+/// #[pallet]
+/// #[index(0)]
+/// type Sudo = custom_sudo_crate; // Takes the index left by the excluded Sudo
+/// ```
+///
+/// # Index manifest
+/// The assigned indices are checked against the manifest at the given path, relative to the runtime crate, and any
+/// difference is a compile error. A missing manifest is an error too, building with `OZ_WRITE_PALLET_INDICES=1` writes
+/// it with the current indices.
+/// ```ignore
+/// #[openzeppelin_construct_runtime(pallet_indices = "pallet_indices.toml")]
+/// mod runtime { /* ... */ }
+/// ```
+///
+/// # Attributes
+/// Attributes other than `pallet`, `index`, `exclude` and `validate_block`, such as `#[cfg(..)]` or doc comments, are
+/// forwarded to the generated pallet declarations, and `#[cfg(..)]` on an abstraction also gates the code it generates
+/// outside of the runtime.
+/// ```ignore
+/// #[cfg(feature = "evm")]
+/// struct EVM; // Its pallets, `RUNTIME_COMPOSITION` entry and generated items are gated as well
+/// ```
+///
+/// # Derives
+/// The aggregates derived by the runtime can be adjusted with `derive(add(..), remove(..))`.
+/// ```ignore
+/// #[openzeppelin_construct_runtime(derive(add(RuntimeViewFunction), remove(RuntimeTask)))]
+/// mod runtime { /* ... */ }
+/// ```
+///
+/// # Dependencies
+/// Abstractions that depend on pallets of other abstractions report a compile error if those are missing,
+/// e.g. "Governance requires System", or gated by a `#[cfg]` attribute they do not have.
+/// ```ignore
+/// struct System;
+/// struct Governance; // Compiles only together with System
+/// ```
+///
+/// # Validate block
+/// `Consensus` and `Tanssi` register `validate_block`. The block executor can be replaced with `executor` and the
+/// registration can be omitted with `skip`. Tanssi inherent checks use the slot duration configured by
+/// `SystemConfig::SlotDuration`.
+/// ```ignore
+/// #[validate_block(executor = MyExecutor<Runtime, Executive>)]
+/// struct Consensus;
+/// ```
+///
+/// # Types
+/// An optional `mod types {}` generates the standard runtime type aliases: `Address`, `Signature`, `Header`, `Block`,
+/// `SignedExtra`, `UncheckedExtrinsic`, `CheckedExtrinsic`, `Migrations` and `Executive`. Any of them can be replaced by
+/// declaring it inside the module. The default `Header` uses the `BlockNumber` type of the runtime, which has to be in
+/// scope. `#[cfg(..)]` attributes of `mod types` are forwarded to every alias.
+/// ```ignore
+/// mod types {
+///     type Migrations = (MyMigration,); // Replaces the default `()`
+/// }
+/// ```
+///
+/// # Composition
+/// The macro also generates `RUNTIME_COMPOSITION`, listing every abstraction of the runtime along with the version of
+/// the abstractions crate and the names and indices of its pallets. Excluded pallets and cfg-disabled abstractions are
+/// omitted.
+/// ```ignore
+/// let names = RUNTIME_COMPOSITION.iter().map(|abstraction| abstraction.name);
+/// ```
+///
 /// Example:
 /// ```
 /// #[openzeppelin_construct_runtime]
 /// mod runtime {
-///     struct System; // Available names are System, Consensus, XCM, Assets, Governance, EVM.
+///     struct System; // Available names are System, Consensus, XCM, Assets, Governance, EVM, Tanssi.
+///     #[index(30)]
+///     struct XCM; // XCM pallets take indices starting from 30
+///     #[exclude(Sudo)]
+///     struct Governance; // All governance pallets except Sudo
 ///     #[pallet]
 ///     type Pallet = pallet_crate; // It mimics the second version of construct runtime macro, but without the pallet_index assignment
+///     #[pallet]
+///     type ForeignAssets = pallet_assets<Instance2>; // Paths, generics and instances are passed through as is
+///     #[pallet]
+///     #[index(50)]
+///     type PinnedPallet = pinned_pallet_crate;
+///     #[pallet]
+///     #[index(0)]
+///     type Sudo = custom_sudo_crate; // Takes the index left by the excluded Sudo
 /// }
 /// ```
 ///
+/// Abstraction names are matched exactly, an unknown name is reported with the closest valid one.
+///
 /// Supported abstractions and pallets inside them:
 /// * `System`:
 ///     * `frame_system`
@@ -65,13 +166,18 @@ mod runtime_apis;
 ///     * `pallet_evm_chain_id`
 ///     * `pallet_erc20_xcm_bridge`
 #[proc_macro_attribute]
-pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> TokenStream {
-    construct_runtime::construct_openzeppelin_runtime(tokens)
+pub fn openzeppelin_construct_runtime(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    construct_runtime::construct_openzeppelin_runtime(args, tokens)
 }
 
 /// Runtime API macro abstraction
 /// This macro wraps the `impl_runtime_api` macro and provides our implementations of them.
 /// It also works in the same groupings as our abstractions pallets and to get the runtime API implementations you only need to provide some types.
+/// The types can be any type path, e.g. `type Executive = crate::Executive;` or `type System = frame_system::Pallet<Runtime>;`.
+/// An impl block of a trait the macro also implements replaces the generated implementation, e.g. implementing
+/// `sp_genesis_builder::GenesisBuilder<Block>` in the module customizes the genesis presets while the other system APIs
+/// are still generated. Traits are matched by path without generic arguments, and a shorter imported path such as
+/// `GenesisBuilder<Block>` matches as well.
 ///
 /// Example:
 /// ```
@@ -93,11 +199,13 @@ pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> To
 /// }
 /// ```
 ///
-/// Supported abstractions:
-/// * `EVM`
+/// Supported abstractions, their module names are lowercase:
+/// * `evm`
 ///   * Implemented APIs:
 ///     * `fp_rpc::EthereumRuntimeRPCApi`
 ///     * `fp_rpc::ConvertTransactionRuntimeApi`
+///     * `moonbeam_rpc_primitives_debug::DebugRuntimeApi` and `moonbeam_rpc_primitives_txpool::TxPoolRuntimeApi`
+///       (under `evm-tracing` feature), tracing the replayed Ethereum transactions with `moonbeam_evm_tracer`
 ///   * Required Types:
 ///     * `RuntimeCall` -- runtime call generated by `construct_runtime` macro
 ///     * `Executive` -- `frame_executive::Executive` specification used by parachain system
@@ -106,20 +214,32 @@ pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> To
 ///   * Implemented APIs:
 ///     * `pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi`
 ///     * `pallet_transaction_payment_rpc_runtime_api::TransactionPaymentCallApi`
+///     * `pallet_assets::AssetsApi` (with the asset types)
+///     * `openzeppelin_pallet_abstractions::runtime_api::ForeignAssetsApi` (with the asset types)
 ///   * Required Types:
 ///     * `TransactionPayment` -- `pallet_transaction_payment` struct pallet generated by `construct_runtime` macro
 ///     * `RuntimeCall` -- runtime call generated by `construct_runtime` macro
 ///     * `Balance` -- type used for balance specification (e.g. in `pallet_balances` config)
+///   * Asset types (optional) -- declared together by runtimes with `pallet_assets` and `pallet_asset_manager`,
+///     declaring only some of them is an error:
+///     * `Assets` -- `pallet_assets` pallet struct generated by `construct_runtime` macro
+///     * `AssetManager` -- `pallet_asset_manager` pallet struct generated by `construct_runtime` macro
+///     * `AssetId` -- type that describes internal asset id (e.g `AssetId` passed to `AssetsConfig`)
+///     * `AssetType` -- type that describes foreign assets (e.g. the one passed to `AssetType` field in `AssetsConfig`)
+///     * `AccountId` -- account id type that was specified in `frame_system::Config`
 /// * `consensus`
 ///   * Implemented APIs:
 ///     * `sp_consensus_aura::AuraApi`
 ///     * `sp_session::SessionKeys`
-///     * `cumulus_primitives_aura::AuraUnincludedSegmentApi` (if `async-backing` feature is enabled)
+///     * `cumulus_primitives_aura::AuraUnincludedSegmentApi` (with `AsyncBacking` or `ElasticScaling` mode)
+///     * `cumulus_primitives_core::GetCoreSelectorApi` (with `ElasticScaling` mode)
 ///   * Required Types:
 ///     * `SessionKeys` -- struct generated by `impl_opaque_keys` macro
-///     * `Aura` -- `pallet_aura` struct pallet generated by `construct_runtime` macro (only if `async-backing` feature is not enabled)
-///     * `SlotDuration` -- constant that is use for slot duration definition (only if `async-backing` feature is enabled)
-///     * `ConsensusHook` -- type that is used in `cumulus_pallet_parachain_system::Config::ConsensusHook` (only if `async-backing` feature is enabled)
+///     * `ConsensusMode` (optional) -- `SyncBacking` (default), `AsyncBacking` or `ElasticScaling`, the mode set as `SystemConfig::ConsensusMode`
+///     * `Aura` -- `pallet_aura` struct pallet generated by `construct_runtime` macro (only with `SyncBacking` mode)
+///     * `SlotDuration` -- constant that is use for slot duration definition (only with `AsyncBacking` or `ElasticScaling` mode)
+///     * `ConsensusHook` -- type that is used in `cumulus_pallet_parachain_system::Config::ConsensusHook` (only with `AsyncBacking` or `ElasticScaling` mode)
+///     * `ParachainSystem` -- `cumulus_pallet_parachain_system` pallet struct generated by `construct_runtime` macro (only with `ElasticScaling` mode)
 /// * `system`
 ///   * Implemented APIs:
 ///     * `sp_api::Core`
@@ -140,27 +260,114 @@ pub fn openzeppelin_construct_runtime(_: TokenStream, tokens: TokenStream) -> To
 ///     * `Nonce` -- nonce type that was specified in `frame_system::Config`
 ///     * `RuntimeGenesisConfig` -- type generated by `construct_runtime` macro.
 ///     * `RuntimeBlockWeights` -- type implementing `Get<BlockWeights>`, often built by `BlockWeights::builder`
+///   * Optional Types:
+///     * `GenesisPresets` -- module providing `get_preset` and `preset_names`, used by `GenesisBuilder` instead of the generated presets
+///     * `DevAccountPair` -- key pair the development accounts of generated presets are derived from, `sp_core::sr25519::Pair` by default
+///     * `ParachainId` -- constant with the parachain id of generated presets, 1000 by default
+///     * `EvmChainId` -- constant with the EVM chain id of generated presets
+///
+///   Without `GenesisPresets`, `development` and `local_testnet` presets are generated: they endow the development
+///   accounts, use Alice (and Bob for `local_testnet`) as invulnerable collators with their Aura session keys, and Alice
+///   as sudo key. Only the pallets present in the runtime are configured. They require `serde_json` and `sp_core`
+///   with the `full_crypto` feature.
+/// * `xcm`
+///   * Implemented APIs:
+///     * `xcm_runtime_apis::fees::XcmPaymentApi`
+///     * `xcm_runtime_apis::dry_run::DryRunApi`
+///     * `xcm_runtime_apis::conversions::LocationToAccountApi`, converting locations as `pallet_xcm::Config::SovereignAccountOf`
+///   * Required Types:
+///     * `XCMConfig` -- struct that implements `xcm_executor::Config`, its `Weigher` computes the weight of messages. If you are using pallet abstractions it is generated by XCM abstraction and called `XcmExecutorConfig`
+///     * `XcmRouter` -- router used to compute delivery fees. If you are using pallet abstractions it is generated by XCM abstraction with the same name
+///     * `XcmWeightTrader` -- `pallet_xcm_weight_trader` pallet struct generated by `construct_runtime` macro, its supported assets are the acceptable fee assets
+///     * `RuntimeCall` -- runtime call generated by `construct_runtime` macro
+///     * `RuntimeEvent` -- runtime event generated by `construct_runtime` macro
+///     * `OriginCaller` -- origin caller generated by `construct_runtime` macro
+///     * `AccountId` -- account id type that was specified in `frame_system::Config`
+/// * `composition`
+///   * Implemented APIs:
+///     * `openzeppelin_pallet_abstractions::runtime_api::OpenZeppelinRuntimeApi`
+///   * Required Types: none, it reports the `RUNTIME_COMPOSITION` constant generated by `openzeppelin_construct_runtime`
 /// * `benchmarks`
 ///   * Implemented APIs:
 ///     * `frame_benchmarking::Benchmark` (under `runtime-benchmarks` feature)
 ///   * Required Types:
-///     * `Assets` -- `palet_assets` pallet struct generated by `construct_runtime` macro
-///     * `AssetManager` -- `pallet_asset_manager` pallet struct generated by `construct_runtime` macro
-///     * `AssetType` -- struct that describes foreign assets in XCM configuration (e.g. the one that was passed to `AssetType` field in `AssetsConfig`)
-///     * `RuntimeOrigin` -- type generated by `construct_runtime` macro
 ///     * `RelayLocation` -- `Location` type pointing to the relaychain
 ///     * `System` -- `frame_system` pallet struct generated by `construct_runtime` macro
 ///     * `ParachainSystem` -- `cumulus_pallet_parachain_system` pallet struct generated by `construct_runtime` macro
 ///     * `ExistentialDeposit` -- type that describes existential deposit (e.g. the one passed to `SystemConfig`)
-///     * `AssetId` -- type that describes internal asset id (e.g `AssetId` passet to `AssetsConfig`)
 ///     * `XCMConfig` -- struct that implements `xcm_executor::Config`. If you are using pallet abstractions it is generated by XCM abstraction and called `XcmExecutorConfig`
-///     * `AccountId` -- account id type that was specified in `frame_system::Config`
 ///     * `Cents` -- constant that represents 1/100 of your native token.
 ///     * `FeeAssetId` -- type that describes an asset to pay XCM fees in. If you used an abstraction macro for XCM support, it was generated along the way and named `FeeAssetId`.
 ///     * `TransactionByteFee` -- type that describes fee per byte of data. If you used an abstraction macro for assets support it was generated with the same name.
-///     * `Address` -- type that describes address format for describing accounts.
 ///     * `Balances` -- `pallet_balances` pallet struct generated by `construct_runtime` macro
+///     * `NativeLocation` -- `Location` of the native currency (e.g. `XcmConfig::SelfReserve`), only with the XCM
+///       transfers of the native currency
+///   * Asset types -- the XCM transfers are benchmarked with foreign assets of `pallet_asset_manager` if
+///     `pallet_asset_manager` is benchmarked (e.g. by `struct Assets`) or any of `Assets`, `AssetManager` and
+///     `AssetType` is declared, all of them being required then, and with the native currency otherwise:
+///     * `Assets` -- `pallet_assets` pallet struct generated by `construct_runtime` macro
+///     * `AssetManager` -- `pallet_asset_manager` pallet struct generated by `construct_runtime` macro
+///     * `AssetType` -- struct that describes foreign assets in XCM configuration (e.g. the one that was passed to `AssetType` field in `AssetsConfig`)
+///     * `RuntimeOrigin` -- type generated by `construct_runtime` macro
+///     * `AssetId` -- type that describes internal asset id (e.g `AssetId` passed to `AssetsConfig`)
+///     * `AccountId` -- account id type that was specified in `frame_system::Config`
+///     * `Address` -- type that describes address format for describing accounts.
+///
+///     The `pallet_xcm` benchmarks are enabled if any of the XCM types or of `Assets`, `AssetManager` and `AssetType`
+///     is declared, the missing ones are reported. Without foreign assets, the complex transfer is a reserve transfer of the native currency paying its
+///     own fees, as it is the only asset the runtime holds.
+///   * `use` items (optional) -- imports of `list_benchmarks!`, `add_benchmarks!` and the types they refer to, replacing
+///     `use crate::{*, types::*, configs::*};` of the runtime templates layout
+///   * Benchmarked pallets (optional):
+///     * structs named after abstractions, e.g. `#[exclude(Proxy)] struct System;`, list the pallets of `BENCHMARKED_PALLETS` of the abstraction
+///     * `#[pallet] type Template = pallet_template;` lists a pallet of the runtime with its module
+///
+///     Without them, the pallets are listed by the `define_benchmarks!` of the runtime. `openzeppelin_runtime!` declares
+///     the abstractions of `mod runtime` unless `mod benchmarks` declares some.
 #[proc_macro_attribute]
 pub fn openzeppelin_runtime_apis(_: TokenStream, input: TokenStream) -> TokenStream {
     runtime_apis::impl_openzeppelin_runtime_apis(input)
 }
+
+/// Unified runtime macro
+/// This macro expands all the layers of the runtime from a single declaration of its abstractions:
+/// * the pallet configurations, by calling `openzeppelin_pallet_abstractions::impl_openzeppelin_*!` with `Config`
+///   and the exclusions of each abstraction;
+/// * the runtime, the same way as `openzeppelin_construct_runtime` does;
+/// * the runtime APIs of the abstractions, the same way as `openzeppelin_runtime_apis` does. The `composition` API is
+///   always implemented.
+///
+/// The types required by the runtime APIs default to their conventional names, e.g. `type Executive = Executive;` or
+/// `type RuntimeVersion = VERSION;`. `mod apis` is optional, the types declared in it take precedence over the defaults,
+/// and its other items, like additional `impl` blocks or `mod benchmarks`, are passed to `openzeppelin_runtime_apis`.
+/// The config traits of the abstractions (e.g. `SystemConfig`) have to be in scope.
+/// `mod benchmarks` transferring foreign assets in its XCM benchmarks requires `struct Assets;` in the runtime.
+/// This API may change in future releases, it is not yet stabilised.
+///
+/// Example:
+/// ```
+/// openzeppelin_runtime! {
+///     // a type implementing the config traits of all the abstractions
+///     type Config = OpenZeppelinRuntime;
+///
+///     #[openzeppelin_construct_runtime(pallet_indices = "pallet_indices.toml")] // optional arguments
+///     mod runtime {
+///         struct System;
+///         struct Consensus;
+///         struct XCM;
+///         struct Assets;
+///         #[exclude(Sudo)]
+///         struct Governance;
+///     }
+///
+///     mod apis {
+///         mod system {
+///             type RuntimeVersion = RUNTIME_VERSION; // overrides the default `VERSION`
+///         }
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn openzeppelin_runtime(input: TokenStream) -> TokenStream {
+    runtime::impl_openzeppelin_runtime(input)
+}
@@ -1,6 +1,8 @@
 use proc_macro2::Ident;
+use std::fmt;
 use syn::ItemStruct;
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ConstructAbstractions {
     Assets,
     Xcm,
@@ -11,41 +13,141 @@ pub enum ConstructAbstractions {
     Tanssi,
 }
 
-#[derive(Debug)]
-pub enum ConversionError {
-    UnknownAbstraction,
+impl ConstructAbstractions {
+    pub const ALL: [ConstructAbstractions; 7] = [
+        ConstructAbstractions::System,
+        ConstructAbstractions::Consensus,
+        ConstructAbstractions::Xcm,
+        ConstructAbstractions::Assets,
+        ConstructAbstractions::Governance,
+        ConstructAbstractions::Evm,
+        ConstructAbstractions::Tanssi,
+    ];
+
+    /// Abstractions whose pallets are referenced by the configuration of this abstraction.
+    pub fn dependencies(&self) -> &'static [ConstructAbstractions] {
+        match self {
+            // `MessageQueue` and `XcmpQueue` are used by the parachain system configuration
+            ConstructAbstractions::System => &[ConstructAbstractions::Xcm],
+            // `Balances`, `ParachainInfo`, `ParachainSystem`, `Timestamp`, `Scheduler` and `Preimage`,
+            // and the `RuntimeConsensusMode` of the parachain system for Consensus and Tanssi
+            ConstructAbstractions::Assets
+            | ConstructAbstractions::Xcm
+            | ConstructAbstractions::Evm
+            | ConstructAbstractions::Governance
+            | ConstructAbstractions::Consensus
+            | ConstructAbstractions::Tanssi => &[ConstructAbstractions::System],
+        }
+    }
+
+    /// Names and modules of the pallets of this abstraction.
+    pub fn pallets(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            ConstructAbstractions::System => {
+                &openzeppelin_pallet_abstractions::system::PALLET_NAMES
+            }
+            ConstructAbstractions::Consensus => {
+                &openzeppelin_pallet_abstractions::consensus::PALLET_NAMES
+            }
+            ConstructAbstractions::Assets => {
+                &openzeppelin_pallet_abstractions::assets::PALLET_NAMES
+            }
+            ConstructAbstractions::Evm => &openzeppelin_pallet_abstractions::evm::PALLET_NAMES,
+            ConstructAbstractions::Tanssi => {
+                &openzeppelin_pallet_abstractions::tanssi::PALLET_NAMES
+            }
+            ConstructAbstractions::Xcm => &openzeppelin_pallet_abstractions::xcm::PALLET_NAMES,
+            ConstructAbstractions::Governance => {
+                &openzeppelin_pallet_abstractions::governance::PALLET_NAMES
+            }
+        }
+    }
+
+    /// Names of the pallets of this abstraction that have benchmarks.
+    pub fn benchmarked_pallets(&self) -> &'static [&'static str] {
+        match self {
+            ConstructAbstractions::System => {
+                &openzeppelin_pallet_abstractions::system::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Consensus => {
+                &openzeppelin_pallet_abstractions::consensus::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Assets => {
+                &openzeppelin_pallet_abstractions::assets::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Evm => {
+                &openzeppelin_pallet_abstractions::evm::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Tanssi => {
+                &openzeppelin_pallet_abstractions::tanssi::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Xcm => {
+                &openzeppelin_pallet_abstractions::xcm::BENCHMARKED_PALLETS
+            }
+            ConstructAbstractions::Governance => {
+                &openzeppelin_pallet_abstractions::governance::BENCHMARKED_PALLETS
+            }
+        }
+    }
+
+    /// Runtime API abstraction implemented for this abstraction, if any.
+    pub fn api(&self) -> Option<APIAbstractions> {
+        match self {
+            ConstructAbstractions::System => Some(APIAbstractions::System),
+            ConstructAbstractions::Consensus => Some(APIAbstractions::Consensus),
+            ConstructAbstractions::Assets => Some(APIAbstractions::Assets),
+            ConstructAbstractions::Evm => Some(APIAbstractions::Evm),
+            ConstructAbstractions::Tanssi => Some(APIAbstractions::Tanssi),
+            ConstructAbstractions::Xcm => Some(APIAbstractions::Xcm),
+            ConstructAbstractions::Governance => None,
+        }
+    }
 }
 
-impl TryFrom<ItemStruct> for ConstructAbstractions {
-    type Error = ConversionError;
-    fn try_from(value: ItemStruct) -> Result<Self, Self::Error> {
-        ConstructAbstractions::try_from(value.ident)
+impl fmt::Display for ConstructAbstractions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ConstructAbstractions::Assets => "Assets",
+            ConstructAbstractions::Xcm => "XCM",
+            ConstructAbstractions::Evm => "EVM",
+            ConstructAbstractions::System => "System",
+            ConstructAbstractions::Governance => "Governance",
+            ConstructAbstractions::Consensus => "Consensus",
+            ConstructAbstractions::Tanssi => "Tanssi",
+        };
+        f.write_str(name)
     }
 }
 
-impl TryFrom<Ident> for ConstructAbstractions {
-    type Error = ConversionError;
-    fn try_from(value: Ident) -> Result<Self, Self::Error> {
-        if "Assets".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Assets)
-        } else if "XCM".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Xcm)
-        } else if "EVM".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Evm)
-        } else if "System".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::System)
-        } else if "Governance".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Governance)
-        } else if "Consensus".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Consensus)
-        } else if "Tanssi".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(ConstructAbstractions::Tanssi)
-        } else {
-            Err(ConversionError::UnknownAbstraction)
+impl TryFrom<&ItemStruct> for ConstructAbstractions {
+    type Error = syn::Error;
+    fn try_from(value: &ItemStruct) -> Result<Self, Self::Error> {
+        ConstructAbstractions::try_from(&value.ident)
+    }
+}
+
+impl TryFrom<&Ident> for ConstructAbstractions {
+    type Error = syn::Error;
+    fn try_from(value: &Ident) -> Result<Self, Self::Error> {
+        if let Some(abstraction) = ConstructAbstractions::ALL
+            .into_iter()
+            .find(|abstraction| value == &abstraction.to_string())
+        {
+            return Ok(abstraction);
         }
+
+        let message = if value.to_string().eq_ignore_ascii_case("Benchmarks") {
+            format!(
+                "`{value}` is a runtime API abstraction without pallets, declare `mod benchmarks` in `openzeppelin_runtime_apis` instead"
+            )
+        } else {
+            unknown_abstraction(value, ConstructAbstractions::ALL)
+        };
+        Err(syn::Error::new_spanned(value, message))
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum APIAbstractions {
     Benchmarks,
     System,
@@ -53,25 +155,137 @@ pub enum APIAbstractions {
     Consensus,
     Assets,
     Tanssi,
+    Xcm,
+    Composition,
 }
 
-impl TryFrom<Ident> for APIAbstractions {
-    type Error = ConversionError;
-    fn try_from(value: Ident) -> Result<Self, Self::Error> {
-        if "Benchmarks".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::Benchmarks)
-        } else if "Assets".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::Assets)
-        } else if "EVM".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::Evm)
-        } else if "System".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::System)
-        } else if "Consensus".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::Consensus)
-        } else if "Tanssi".eq_ignore_ascii_case(&value.to_string()) {
-            Ok(APIAbstractions::Tanssi)
-        } else {
-            Err(ConversionError::UnknownAbstraction)
+impl TryFrom<&Ident> for APIAbstractions {
+    type Error = syn::Error;
+    fn try_from(value: &Ident) -> Result<Self, Self::Error> {
+        if let Some(abstraction) = APIAbstractions::ALL
+            .into_iter()
+            .find(|abstraction| value == &abstraction.to_string())
+        {
+            return Ok(abstraction);
         }
+
+        let without_api = ConstructAbstractions::ALL.into_iter().find(|abstraction| {
+            abstraction.api().is_none()
+                && value
+                    .to_string()
+                    .eq_ignore_ascii_case(&abstraction.to_string())
+        });
+        let message = match without_api {
+            Some(abstraction) => format!(
+                "`{abstraction}` abstraction does not provide runtime APIs, remove `mod {value}`"
+            ),
+            None => unknown_abstraction(value, APIAbstractions::ALL),
+        };
+        Err(syn::Error::new_spanned(value, message))
+    }
+}
+
+impl APIAbstractions {
+    pub const ALL: [APIAbstractions; 8] = [
+        APIAbstractions::System,
+        APIAbstractions::Consensus,
+        APIAbstractions::Xcm,
+        APIAbstractions::Assets,
+        APIAbstractions::Evm,
+        APIAbstractions::Tanssi,
+        APIAbstractions::Benchmarks,
+        APIAbstractions::Composition,
+    ];
+}
+
+impl fmt::Display for APIAbstractions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            APIAbstractions::Benchmarks => "benchmarks",
+            APIAbstractions::System => "system",
+            APIAbstractions::Evm => "evm",
+            APIAbstractions::Consensus => "consensus",
+            APIAbstractions::Assets => "assets",
+            APIAbstractions::Tanssi => "tanssi",
+            APIAbstractions::Xcm => "xcm",
+            APIAbstractions::Composition => "composition",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Describes an unknown abstraction name, suggesting the closest of the valid `names`.
+fn unknown_abstraction<T: fmt::Display>(
+    value: &Ident,
+    names: impl IntoIterator<Item = T>,
+) -> String {
+    let names: Vec<_> = names.into_iter().map(|name| name.to_string()).collect();
+    let value = value.to_string();
+    let suggestion = names
+        .iter()
+        .map(|name| {
+            let similarity = strsim::jaro_winkler(&value.to_lowercase(), &name.to_lowercase());
+            (similarity, name)
+        })
+        .filter(|(similarity, _)| *similarity > 0.8)
+        .max_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let mut message = format!("unknown abstraction `{value}`");
+    match suggestion {
+        Some((_, name)) => message.push_str(&format!(", did you mean `{name}`? Expected")),
+        None => message.push_str(", expected"),
+    }
+    message.push_str(&format!(" one of: {}", names.join(", ")));
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::format_ident;
+
+    use super::*;
+
+    #[test]
+    fn suggests_similar_abstraction() {
+        let message = unknown_abstraction(&format_ident!("Sytem"), ["System", "Consensus", "XCM"]);
+        assert_eq!(
+            message,
+            "unknown abstraction `Sytem`, did you mean `System`? Expected one of: System, Consensus, XCM"
+        );
+    }
+
+    #[test]
+    fn suggests_ignoring_case() {
+        let message = unknown_abstraction(&format_ident!("xcm"), ["System", "XCM"]);
+        assert!(message.contains("did you mean `XCM`?"));
+    }
+
+    #[test]
+    fn lists_abstractions_without_suggestion() {
+        let message = unknown_abstraction(&format_ident!("Staking"), ["System", "XCM"]);
+        assert_eq!(
+            message,
+            "unknown abstraction `Staking`, expected one of: System, XCM"
+        );
+    }
+
+    #[test]
+    fn parses_known_abstractions() {
+        for abstraction in ConstructAbstractions::ALL {
+            let ident = format_ident!("{}", abstraction.to_string());
+            assert!(ConstructAbstractions::try_from(&ident).is_ok());
+        }
+        for abstraction in APIAbstractions::ALL {
+            let ident = format_ident!("{}", abstraction.to_string());
+            assert!(APIAbstractions::try_from(&ident).is_ok());
+        }
+    }
+
+    #[test]
+    fn redirects_misplaced_abstractions() {
+        let error = ConstructAbstractions::try_from(&format_ident!("benchmarks"))
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("declare `mod benchmarks`"));
     }
 }
@@ -0,0 +1,188 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::{collections::BTreeMap, path::PathBuf};
+use syn::{Ident, LitStr};
+
+use crate::errors::Errors;
+
+/// Environment variable that makes the macro write the manifest instead of checking it, when set to `1`.
+const WRITE_VAR: &str = "OZ_WRITE_PALLET_INDICES";
+
+/// Checks the computed pallet indices against the manifest at `path`, relative to the runtime crate.
+///
+/// A missing manifest is an error, it is written with the computed indices only when `OZ_WRITE_PALLET_INDICES=1`.
+/// Returns the tokens that make the compiler track the manifest, so that changing it triggers a rebuild.
+pub fn check(path: &LitStr, indices: &BTreeMap<u32, Ident>) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
+        .map_err(|_| syn::Error::new_spanned(path, "`CARGO_MANIFEST_DIR` is not set"))?;
+    let full_path = PathBuf::from(manifest_dir).join(path.value());
+
+    if std::env::var_os(WRITE_VAR).is_some_and(|value| value == "1") {
+        std::fs::write(&full_path, render(indices)).map_err(|e| {
+            syn::Error::new_spanned(path, format!("failed to write pallet indices: {e}"))
+        })?;
+    } else if full_path.exists() {
+        let content = std::fs::read_to_string(&full_path).map_err(|e| {
+            syn::Error::new_spanned(path, format!("failed to read pallet indices: {e}"))
+        })?;
+        let manifest = parse(path, &content)?;
+        compare(path, &manifest, indices)?;
+    } else {
+        return Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "pallet indices manifest `{}` not found, build with `{WRITE_VAR}=1` to write it",
+                full_path.display()
+            ),
+        ));
+    }
+
+    let full_path = full_path.to_string_lossy();
+    Ok(quote! {
+        const _: &str = include_str!(#full_path);
+    })
+}
+
+fn parse(path: &LitStr, content: &str) -> syn::Result<BTreeMap<String, u32>> {
+    let table: toml::Table = content.parse().map_err(|e| {
+        syn::Error::new_spanned(path, format!("failed to parse pallet indices: {e}"))
+    })?;
+    table
+        .into_iter()
+        .map(
+            |(name, value)| match value.as_integer().map(u32::try_from) {
+                Some(Ok(index)) => Ok((name, index)),
+                _ => Err(syn::Error::new_spanned(
+                    path,
+                    format!("index of pallet `{name}` is not a valid pallet index: {value}"),
+                )),
+            },
+        )
+        .collect()
+}
+
+fn compare(
+    path: &LitStr,
+    manifest: &BTreeMap<String, u32>,
+    indices: &BTreeMap<u32, Ident>,
+) -> syn::Result<()> {
+    let mut errors = Errors::default();
+    let file = path.value();
+
+    for (index, name) in indices {
+        match manifest.get(&name.to_string()) {
+            Some(recorded) if recorded == index => (),
+            Some(recorded) => errors.push(syn::Error::new_spanned(
+                name,
+                format!(
+                    "index of pallet `{name}` changed from {recorded} to {index}, pin it with `#[index({recorded})]` or update `{file}`, e.g. by building with `{WRITE_VAR}=1`, if the change is intended"
+                ),
+            )),
+            None => errors.push(syn::Error::new_spanned(
+                name,
+                format!("pallet `{name}` with index {index} is not recorded in `{file}`"),
+            )),
+        }
+    }
+
+    for (name, recorded) in manifest {
+        if !indices.values().any(|pallet| pallet == name) {
+            errors.push(syn::Error::new_spanned(
+                path,
+                format!("pallet `{name}` with index {recorded} is recorded in `{file}`, but is not present in the runtime"),
+            ));
+        }
+    }
+
+    errors.finish()?;
+    Ok(())
+}
+
+fn render(indices: &BTreeMap<u32, Ident>) -> String {
+    let mut content = String::from(
+        "# Pallet indices of the runtime, checked by `openzeppelin_construct_runtime`.\n\
+         # Build with `OZ_WRITE_PALLET_INDICES=1` to regenerate it from the current runtime.\n",
+    );
+    for (index, name) in indices {
+        content.push_str(&format!("{name} = {index}\n"));
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::format_ident;
+    use syn::parse_quote;
+
+    use super::*;
+
+    fn indices(pallets: &[(u32, &str)]) -> BTreeMap<u32, Ident> {
+        pallets
+            .iter()
+            .map(|(index, name)| (*index, format_ident!("{}", name)))
+            .collect()
+    }
+
+    #[test]
+    fn rendered_manifest_matches() {
+        let path: LitStr = parse_quote!("pallet_indices.toml");
+        let indices = indices(&[(0, "System"), (10, "Balances")]);
+        let content = render(&indices);
+        assert!(content.ends_with("System = 0\nBalances = 10\n"));
+        let manifest = parse(&path, &content).unwrap();
+        assert!(compare(&path, &manifest, &indices).is_ok());
+    }
+
+    #[test]
+    fn reports_changed_index() {
+        let path: LitStr = parse_quote!("pallet_indices.toml");
+        let manifest = parse(&path, "System = 0\nBalances = 10\n").unwrap();
+        let error = compare(
+            &path,
+            &manifest,
+            &indices(&[(0, "System"), (11, "Balances")]),
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "index of pallet `Balances` changed from 10 to 11, pin it with `#[index(10)]` or update `pallet_indices.toml`, e.g. by building with `OZ_WRITE_PALLET_INDICES=1`, if the change is intended"
+        );
+    }
+
+    #[test]
+    fn reports_added_and_removed_pallets() {
+        let path: LitStr = parse_quote!("pallet_indices.toml");
+        let manifest = parse(&path, "System = 0\nSudo = 1\n").unwrap();
+        let errors: Vec<_> = compare(&path, &manifest, &indices(&[(0, "System"), (1, "Proxy")]))
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                "pallet `Proxy` with index 1 is not recorded in `pallet_indices.toml`",
+                "pallet `Sudo` with index 1 is recorded in `pallet_indices.toml`, but is not present in the runtime",
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_manifest() {
+        let path: LitStr = parse_quote!("missing_pallet_indices.toml");
+        let error = check(&path, &indices(&[(0, "System")])).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("not found, build with `OZ_WRITE_PALLET_INDICES=1` to write it"));
+        assert!(!PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join(path.value())
+            .exists());
+    }
+
+    #[test]
+    fn rejects_invalid_index() {
+        let path: LitStr = parse_quote!("pallet_indices.toml");
+        assert!(parse(&path, "System = -1\n").is_err());
+        assert!(parse(&path, "System = \"0\"\n").is_err());
+    }
+}
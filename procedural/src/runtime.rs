@@ -0,0 +1,381 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, File, Ident, Item, ItemMod, ItemStruct, Meta, Type};
+
+use crate::{
+    apis::{mod_items, transfers_foreign_assets},
+    construct_runtime::{construct_runtime, parse_exclusions},
+    errors::Errors,
+    models::{APIAbstractions, ConstructAbstractions},
+    runtime_apis::impl_runtime_apis,
+};
+
+pub fn impl_openzeppelin_runtime(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as File);
+    impl_runtime(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn impl_runtime(input: &File) -> syn::Result<proc_macro2::TokenStream> {
+    let mut errors = Errors::default();
+    let mut config = None;
+    let mut runtime = None;
+    let mut apis = None;
+
+    for item in input.items.iter() {
+        match item {
+            Item::Type(ty) if ty.ident == "Config" => config = Some(ty.ty.as_ref()),
+            Item::Mod(m) if m.ident == "runtime" => runtime = Some(m.clone()),
+            Item::Mod(m) if m.ident == "apis" => apis = Some(m.clone()),
+            item => errors.push(syn::Error::new_spanned(
+                item,
+                "unexpected item, expected `type Config`, `mod runtime` or `mod apis`",
+            )),
+        }
+    }
+    let config = errors.expect(config, || {
+        syn::Error::new(
+            Span::call_site(),
+            "`type Config` not specified, but required",
+        )
+    });
+    let runtime = errors.expect(runtime, || {
+        syn::Error::new(
+            Span::call_site(),
+            "`mod runtime` not specified, but required",
+        )
+    });
+    let checked = errors.finish()?;
+    let (config, mut runtime) = (config.get(&checked)?, runtime.get(&checked)?);
+
+    let construct_args = take_macro_args(&mut runtime, "openzeppelin_construct_runtime")?;
+    let mut errors = Errors::default();
+    let mut configs = quote! {};
+    let mut abstractions = vec![];
+    let mut abstraction_items = vec![];
+    for item in mod_items(&runtime)? {
+        if let Item::Struct(item) = item {
+            // Unknown abstractions are reported by `construct_runtime`
+            let Ok(abstraction) = ConstructAbstractions::try_from(item) else {
+                continue;
+            };
+            abstractions.push(abstraction);
+            abstraction_items.push(item);
+            if let Some(tokens) = errors.collect(impl_abstraction(abstraction, item, config)) {
+                configs.extend(tokens);
+            }
+        }
+    }
+    let construct = errors.collect(construct_runtime(construct_args, &runtime));
+    let apis = errors.collect(construct_apis(apis, &abstractions, &abstraction_items));
+    errors.finish()?;
+
+    Ok(quote! {
+        #configs
+        #construct
+        #apis
+    })
+}
+
+/// Calls the `impl_openzeppelin_*` macro configuring the pallets of the abstraction.
+fn impl_abstraction(
+    abstraction: ConstructAbstractions,
+    item: &ItemStruct,
+    config: &Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let cfgs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+    let excluded = parse_exclusions(&item.attrs)?;
+    let name = format_ident!(
+        "impl_openzeppelin_{}",
+        abstraction.to_string().to_lowercase()
+    );
+    Ok(quote! {
+        #(#cfgs)*
+        openzeppelin_pallet_abstractions::#name!(#config, exclude(#(#excluded),*));
+    })
+}
+
+/// Implements the runtime APIs of the abstractions, filling the types not provided in `mod apis`
+/// with their conventional names.
+fn construct_apis(
+    apis: Option<ItemMod>,
+    abstractions: &[ConstructAbstractions],
+    abstraction_items: &[&ItemStruct],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut apis = match apis {
+        Some(mut apis) => {
+            take_macro_args(&mut apis, "openzeppelin_runtime_apis")?;
+            apis
+        }
+        None => parse_quote!(
+            mod apis {}
+        ),
+    };
+
+    let mut items: Vec<Item> = mod_items(&apis)?.to_vec();
+    with_defaults(&mut items, &[("Runtime", "Runtime"), ("Block", "Block")]);
+
+    let evm = abstractions.contains(&ConstructAbstractions::Evm);
+    let foreign_assets = has_foreign_assets(abstractions, abstraction_items)?;
+    let mut errors = Errors::default();
+    let mut provided = vec![];
+    for item in items.iter_mut() {
+        if let Item::Mod(m) = item {
+            if let Some(api) = errors.collect(APIAbstractions::try_from(&m.ident)) {
+                provided.push(api);
+                if let Some((_, content)) = &mut m.content {
+                    with_api_defaults(content, api, evm, foreign_assets);
+                    if api == APIAbstractions::Benchmarks {
+                        with_benchmarked_abstractions(content, abstraction_items);
+                    }
+                }
+                // The XCM benchmark helpers transfer foreign assets through `Assets` and `AssetManager`
+                if api == APIAbstractions::Benchmarks
+                    && !abstractions.contains(&ConstructAbstractions::Assets)
+                    && errors.collect(transfers_foreign_assets(m)) == Some(true)
+                {
+                    errors.push(syn::Error::new_spanned(
+                        &m.ident,
+                        "benchmarks transferring foreign assets require Assets, add `struct Assets;` to the runtime",
+                    ));
+                }
+            }
+        }
+    }
+    errors.finish()?;
+
+    // The composition of the runtime is always reported
+    let required = abstractions
+        .iter()
+        .filter_map(ConstructAbstractions::api)
+        .chain([APIAbstractions::Composition]);
+    for api in required {
+        if !provided.contains(&api) {
+            let name = Ident::new(&api.to_string(), Span::call_site());
+            let mut content = vec![];
+            with_api_defaults(&mut content, api, evm, foreign_assets);
+            items.push(parse_quote! {
+                mod #name {
+                    #(#content)*
+                }
+            });
+        }
+    }
+
+    apis.content = Some((Default::default(), items));
+    impl_runtime_apis(&apis)
+}
+
+/// Adds the default types of the API abstraction that are not declared in `items`.
+fn with_api_defaults(items: &mut Vec<Item>, api: APIAbstractions, evm: bool, foreign_assets: bool) {
+    with_defaults(items, default_api_types(api));
+    // Development accounts of EVM runtimes are derived from ECDSA keys
+    if api == APIAbstractions::System && evm {
+        with_defaults(items, &[("DevAccountPair", "sp_core::ecdsa::Pair")]);
+    }
+    if api == APIAbstractions::Assets && foreign_assets {
+        with_defaults(
+            items,
+            &[
+                ("Assets", "Assets"),
+                ("AssetManager", "AssetManager"),
+                ("AssetId", "AssetId"),
+                ("AssetType", "AssetType"),
+                ("AccountId", "AccountId"),
+            ],
+        );
+    }
+}
+
+/// Whether the runtime has both `pallet_assets` and `pallet_asset_manager`, used by the asset APIs.
+fn has_foreign_assets(
+    abstractions: &[ConstructAbstractions],
+    abstraction_items: &[&ItemStruct],
+) -> syn::Result<bool> {
+    for (abstraction, item) in abstractions.iter().zip(abstraction_items) {
+        if *abstraction == ConstructAbstractions::Assets {
+            let excluded = parse_exclusions(&item.attrs)?;
+            return Ok(!excluded
+                .iter()
+                .any(|name| name == "Assets" || name == "AssetManager"));
+        }
+    }
+    Ok(false)
+}
+
+/// Benchmarks the pallets of the abstractions of the runtime, unless `mod benchmarks` declares the
+/// benchmarked abstractions itself.
+fn with_benchmarked_abstractions(items: &mut Vec<Item>, abstraction_items: &[&ItemStruct]) {
+    if items.iter().any(|item| matches!(item, Item::Struct(_))) {
+        return;
+    }
+    for item in abstraction_items {
+        let attrs = item
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg") || attr.path().is_ident("exclude"));
+        let ident = &item.ident;
+        items.push(parse_quote! {
+            #(#attrs)*
+            struct #ident;
+        });
+    }
+}
+
+/// Adds `type #name = #value;` for every default type that is not declared in `items`.
+fn with_defaults(items: &mut Vec<Item>, defaults: &[(&str, &str)]) {
+    for (name, value) in defaults {
+        let declared = items
+            .iter()
+            .any(|item| matches!(item, Item::Type(ty) if ty.ident == name));
+        if !declared {
+            let name = Ident::new(name, Span::call_site());
+            let value: Type = syn::parse_str(value).expect("default types are valid paths");
+            items.push(parse_quote!(type #name = #value;));
+        }
+    }
+}
+
+/// Conventional names of the types required by the API abstraction.
+fn default_api_types(api: APIAbstractions) -> &'static [(&'static str, &'static str)] {
+    match api {
+        APIAbstractions::System => &[
+            ("Executive", "Executive"),
+            ("System", "System"),
+            ("ParachainSystem", "ParachainSystem"),
+            ("RuntimeVersion", "VERSION"),
+            ("AccountId", "AccountId"),
+            ("Nonce", "Nonce"),
+            ("RuntimeGenesisConfig", "RuntimeGenesisConfig"),
+            ("RuntimeBlockWeights", "RuntimeBlockWeights"),
+        ],
+        APIAbstractions::Consensus => &[
+            ("SessionKeys", "SessionKeys"),
+            (
+                "ConsensusMode",
+                "openzeppelin_pallet_abstractions::SyncBacking",
+            ),
+            ("Aura", "Aura"),
+            ("SlotDuration", "SLOT_DURATION"),
+            ("ConsensusHook", "ConsensusHook"),
+            ("ParachainSystem", "ParachainSystem"),
+        ],
+        APIAbstractions::Assets => &[
+            ("TransactionPayment", "TransactionPayment"),
+            ("RuntimeCall", "RuntimeCall"),
+            ("Balance", "Balance"),
+        ],
+        APIAbstractions::Evm => &[
+            ("RuntimeCall", "RuntimeCall"),
+            ("Executive", "Executive"),
+            ("Ethereum", "Ethereum"),
+        ],
+        APIAbstractions::Tanssi => &[("SessionKeys", "SessionKeys")],
+        APIAbstractions::Xcm => &[
+            ("XCMConfig", "XcmExecutorConfig"),
+            ("XcmRouter", "XcmRouter"),
+            ("XcmWeightTrader", "XcmWeightTrader"),
+            ("RuntimeCall", "RuntimeCall"),
+            ("RuntimeEvent", "RuntimeEvent"),
+            ("OriginCaller", "OriginCaller"),
+            ("AccountId", "AccountId"),
+        ],
+        APIAbstractions::Benchmarks => &[
+            ("AllPalletsWithSystem", "AllPalletsWithSystem"),
+            ("ParachainSystem", "ParachainSystem"),
+            ("System", "System"),
+        ],
+        APIAbstractions::Composition => &[],
+    }
+}
+
+/// Removes the `#[name(..)]` attribute from the module, returning its arguments.
+fn take_macro_args(item: &mut ItemMod, name: &str) -> syn::Result<proc_macro2::TokenStream> {
+    let (taken, attrs) = std::mem::take(&mut item.attrs)
+        .into_iter()
+        .partition::<Vec<_>, _>(|attr| attr.path().is_ident(name));
+    item.attrs = attrs;
+    match taken.first().map(|attr| &attr.meta) {
+        Some(Meta::List(list)) => Ok(list.tokens.clone()),
+        Some(Meta::NameValue(meta)) => Err(syn::Error::new_spanned(
+            meta,
+            format!("expected `#[{name}]` or `#[{name}(..)]`"),
+        )),
+        Some(Meta::Path(_)) | None => Ok(quote! {}),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(input: File) -> Vec<String> {
+        impl_runtime(&input)
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn consensus_requires_system() {
+        let errors = errors(parse_quote! {
+            type Config = OpenZeppelinRuntime;
+            mod runtime {
+                struct Consensus;
+            }
+        });
+        assert!(errors.contains(
+            &"Consensus requires System, add `struct System;` to the runtime".to_string()
+        ));
+    }
+
+    #[test]
+    fn foreign_assets_benchmarks_require_assets() {
+        let errors = errors(parse_quote! {
+            type Config = OpenZeppelinRuntime;
+            mod runtime {
+                struct System;
+                struct XCM;
+            }
+            mod apis {
+                mod benchmarks {
+                    type AssetManager = AssetManager;
+                }
+            }
+        });
+        assert!(errors.contains(
+            &"benchmarks transferring foreign assets require Assets, add `struct Assets;` to the runtime"
+                .to_string()
+        ));
+    }
+
+    #[test]
+    fn native_benchmarks_accept_common_types() {
+        let input: File = parse_quote! {
+            type Config = OpenZeppelinRuntime;
+            mod runtime {
+                struct System;
+                struct XCM;
+            }
+            mod apis {
+                mod benchmarks {
+                    type RelayLocation = RelayLocation;
+                    type ExistentialDeposit = ExistentialDeposit;
+                    type XCMConfig = XcmExecutorConfig;
+                    type Cents = CENTS;
+                    type FeeAssetId = FeeAssetId;
+                    type TransactionByteFee = TransactionByteFee;
+                    type Balances = Balances;
+                    type NativeLocation = SelfReserve;
+                    type AccountId = AccountId;
+                    type RuntimeOrigin = RuntimeOrigin;
+                    type Address = Address;
+                }
+            }
+        };
+        assert!(impl_runtime(&input).is_ok());
+    }
+}
@@ -1,138 +1,167 @@
 use crate::{
     apis::{
-        self, fetch_ident, AbstractionState, AssetAPIFields, BenchmarkAPIFields,
-        ConsensusAPIFields, EVMAPIFields, SystemAPIFields, TanssiAPIFields,
+        self, fetch_type, mod_items, AbstractionState, AssetAPIFields, BenchmarkAPIFields,
+        ConsensusAPIFields, EVMAPIFields, SystemAPIFields, TanssiAPIFields, XcmAPIFields,
     },
+    errors::Errors,
     models::APIAbstractions,
 };
-use core::panic;
 use proc_macro::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{parse_macro_input, Ident, Item, ItemMod};
+use syn::{parse_macro_input, File, Item, ItemImpl, ItemMod, Path, Type};
 
 pub fn impl_openzeppelin_runtime_apis(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as syn::ItemMod);
-    let Some((_, items)) = input.content else {
-        panic!("no content");
-    };
+    let input = parse_macro_input!(input as ItemMod);
+    impl_runtime_apis(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
+pub fn impl_runtime_apis(input: &ItemMod) -> syn::Result<proc_macro2::TokenStream> {
+    let mut errors = Errors::default();
     let mut inner = quote! {};
+    let mut generated = quote! {};
 
     let mut abstractions = vec![];
+    let mut overridden = vec![];
 
-    let mut runtime: Option<Ident> = None;
-    let mut block: Option<Ident> = None;
+    let mut runtime: Option<Type> = None;
+    let mut block: Option<Type> = None;
 
-    for item in items {
+    for item in mod_items(input)? {
         match item {
             Item::Type(ty) => {
                 if ty.ident == "Runtime" {
-                    runtime = Some(fetch_ident(&ty.ty));
+                    runtime = errors.collect(fetch_type(&ty.ty));
                 } else if ty.ident == "Block" {
-                    block = Some(fetch_ident(&ty.ty));
+                    block = errors.collect(fetch_type(&ty.ty));
                 }
             }
             Item::Mod(m) => abstractions.push(m),
             Item::Impl(im) => {
+                if let Some((_, path, _)) = &im.trait_ {
+                    overridden.push(path);
+                }
                 inner.extend(im.to_token_stream());
             }
             _ => (),
         }
     }
 
-    let runtime = runtime.expect(
-        "Runtime is missing. Please, add `type Runtime = /* Reference to generated runtime */` to the root of the module",
-    );
-    let block = block.expect(
-        "Block is missing. Please, add `type Block = /* Reference to generated runtime */` to the root of the module",
-    );
+    let runtime = errors.require(runtime, &input.ident, "Runtime");
+    let block = errors.require(block, &input.ident, "Block");
+    let checked = errors.finish()?;
+    let (runtime, block) = (runtime.get(&checked)?, block.get(&checked)?);
+    let mut errors = Errors::default();
 
     let mut state = AbstractionState::default();
 
     for abstraction in abstractions {
-        inner.extend(construct_abstraction(
+        if let Some(api) = errors.collect(construct_abstraction(
             abstraction,
             &mut state,
             &runtime,
             &block,
-        ));
+        )) {
+            generated.extend(api);
+        }
     }
+    errors.finish()?;
 
     if let AbstractionState {
         benchmark_fields: Some(fields),
         consensus,
     } = state
     {
-        inner.extend(apis::construct_benchmarking_api(
-            consensus, &runtime, fields,
+        generated.extend(apis::construct_benchmarking_api(
+            consensus, &runtime, &block, fields,
         ));
     }
+    inner.extend(without_overridden(generated, &overridden)?);
 
-    let expanded = quote! {
+    Ok(quote! {
         sp_api::impl_runtime_apis! {
             #inner
         }
-    };
+    })
+}
+
+/// Removes the generated implementations of the traits implemented in `mod apis`.
+///
+/// Traits are compared by their path without generic arguments, a path written in `mod apis` also matches the
+/// generated paths it is a suffix of, e.g. `GenesisBuilder<Block>` matches `sp_genesis_builder::GenesisBuilder<Block>`.
+fn without_overridden(
+    generated: proc_macro2::TokenStream,
+    overridden: &[&Path],
+) -> syn::Result<proc_macro2::TokenStream> {
+    let generated: File = syn::parse2(generated)?;
+    let items = generated.items.into_iter().filter(|item| match item {
+        Item::Impl(ItemImpl {
+            trait_: Some((_, path, _)),
+            ..
+        }) => !overridden
+            .iter()
+            .any(|overridden| is_suffix(overridden, path)),
+        _ => true,
+    });
+    Ok(quote! { #(#items)* })
+}
 
-    TokenStream::from(expanded)
+fn is_suffix(suffix: &Path, path: &Path) -> bool {
+    suffix.segments.len() <= path.segments.len()
+        && suffix
+            .segments
+            .iter()
+            .rev()
+            .zip(path.segments.iter().rev())
+            .all(|(a, b)| a.ident == b.ident)
 }
 
 fn construct_abstraction(
-    item: ItemMod,
+    item: &ItemMod,
     state: &mut AbstractionState,
-    runtime: &Ident,
-    block: &Ident,
-) -> proc_macro2::TokenStream {
-    let abstraction = APIAbstractions::try_from(item.ident).expect("Wrong Abstraction Struct");
-    let (_, content) = item
-        .content
-        .expect("`mod assets` does not have any content.");
-
-    match abstraction {
+    runtime: &Type,
+    block: &Type,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let abstraction = APIAbstractions::try_from(&item.ident)?;
+
+    let api = match abstraction {
         APIAbstractions::Evm => {
             let EVMAPIFields {
                 call,
                 executive,
                 ethereum,
-            } = EVMAPIFields::try_from(content.as_slice()).expect("Error while parsing EVM config");
+            } = EVMAPIFields::try_from(item)?;
 
-            apis::evm_apis(runtime, block, &call, &executive, &ethereum)
+            let mut api = apis::evm_apis(runtime, block, &call, &executive, &ethereum);
+            api.extend(apis::evm_tracing_apis(runtime, block, &call, &executive));
+            api
         }
         APIAbstractions::Assets => {
             let AssetAPIFields {
                 transaction_payment,
                 balance,
                 call,
-            } = AssetAPIFields::try_from(content.as_slice())
-                .expect("Error while parsing assets config");
+                foreign_assets,
+            } = AssetAPIFields::try_from(item)?;
 
-            apis::assets_apis(runtime, block, &transaction_payment, &balance, &call)
+            apis::assets_apis(
+                runtime,
+                block,
+                &transaction_payment,
+                &balance,
+                &call,
+                foreign_assets.as_ref(),
+            )
         }
         APIAbstractions::Consensus => {
             state.consensus = true;
-            #[cfg(not(feature = "async-backing"))]
-            {
-                let ConsensusAPIFields { session_keys, aura } =
-                    ConsensusAPIFields::try_from(content.as_slice())
-                        .expect("Error while parsing Consensus config");
-                apis::consensus_apis(runtime, block, &session_keys, &aura)
-            }
-            #[cfg(feature = "async-backing")]
-            {
-                let ConsensusAPIFields {
-                    session_keys,
-                    slot_duration,
-                    consensus_hook,
-                } = ConsensusAPIFields::try_from(content.as_slice())
-                    .expect("Error while parsing Consensus config");
-                apis::consensus_apis(
-                    runtime,
-                    block,
-                    &session_keys,
-                    &slot_duration,
-                    &consensus_hook,
-                )
-            }
+            let ConsensusAPIFields {
+                session_keys,
+                mode_type,
+                mode,
+            } = ConsensusAPIFields::try_from(item)?;
+            apis::consensus_apis(runtime, block, &session_keys, &mode_type, &mode)
         }
         APIAbstractions::System => {
             let SystemAPIFields {
@@ -144,8 +173,8 @@ fn construct_abstraction(
                 nonce,
                 genesis,
                 runtime_block_weights,
-            } = SystemAPIFields::try_from(content.as_slice())
-                .expect("Error while parsing system config");
+                genesis_presets,
+            } = SystemAPIFields::try_from(item)?;
 
             apis::system_apis(
                 runtime,
@@ -158,19 +187,85 @@ fn construct_abstraction(
                 &nonce,
                 &genesis,
                 &runtime_block_weights,
+                &genesis_presets,
             )
         }
         APIAbstractions::Benchmarks => {
-            let api_fields = BenchmarkAPIFields::try_from(content.as_slice())
-                .expect("Error while parsing benchmarking config");
+            let api_fields = BenchmarkAPIFields::try_from(item)?;
 
             state.benchmark_fields = Some(api_fields);
             quote! {}
         }
+        APIAbstractions::Composition => apis::composition_apis(runtime, block),
         APIAbstractions::Tanssi => {
-            let TanssiAPIFields { session_keys } = TanssiAPIFields::try_from(content.as_slice())
-                .expect("Error while parsing Tanssi config");
+            let TanssiAPIFields { session_keys } = TanssiAPIFields::try_from(item)?;
             apis::tanssi_apis(runtime, block, &session_keys)
         }
+        APIAbstractions::Xcm => {
+            let XcmAPIFields {
+                xcm_config,
+                xcm_router,
+                xcm_weight_trader,
+                call,
+                event,
+                origin_caller,
+                account_id,
+            } = XcmAPIFields::try_from(item)?;
+            apis::xcm_apis(
+                runtime,
+                block,
+                &xcm_config,
+                &xcm_router,
+                &xcm_weight_trader,
+                &call,
+                &event,
+                &origin_caller,
+                &account_id,
+            )
+        }
+    };
+    Ok(api)
+}
+
+#[cfg(test)]
+mod tests {
+    use syn::parse_quote;
+
+    use super::*;
+
+    #[test]
+    fn matches_path_suffix() {
+        let path: Path = parse_quote!(sp_genesis_builder::GenesisBuilder<Block>);
+        assert!(is_suffix(&parse_quote!(GenesisBuilder<Block>), &path));
+        assert!(is_suffix(&parse_quote!(GenesisBuilder), &path));
+        assert!(is_suffix(
+            &parse_quote!(sp_genesis_builder::GenesisBuilder),
+            &path
+        ));
+    }
+
+    #[test]
+    fn rejects_other_paths() {
+        let path: Path = parse_quote!(sp_genesis_builder::GenesisBuilder<Block>);
+        assert!(!is_suffix(&parse_quote!(Builder), &path));
+        assert!(!is_suffix(&parse_quote!(other::GenesisBuilder), &path));
+        assert!(!is_suffix(
+            &parse_quote!(crate::sp_genesis_builder::GenesisBuilder),
+            &path
+        ));
+    }
+
+    #[test]
+    fn removes_overridden_implementations() {
+        let generated = quote! {
+            impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {}
+            impl sp_session::SessionKeys<Block> for Runtime {}
+        };
+        let overridden: Path = parse_quote!(GenesisBuilder<Block>);
+        let remaining = without_overridden(generated, &[&overridden]).unwrap();
+        assert_eq!(
+            remaining.to_string(),
+            quote! { impl sp_session::SessionKeys<Block> for Runtime {} }.to_string()
+        );
     }
 }
@@ -0,0 +1,171 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use syn::{parse_quote, Item, ItemMod, Type};
+
+use crate::{apis::mod_items, errors::Errors};
+
+/// Names of the generated type aliases, in the order of generation.
+const TYPE_NAMES: [&str; 9] = [
+    "Address",
+    "Signature",
+    "Header",
+    "Block",
+    "SignedExtra",
+    "UncheckedExtrinsic",
+    "CheckedExtrinsic",
+    "Migrations",
+    "Executive",
+];
+
+/// Generates the standard runtime type aliases, replacing defaults by the types declared in `mod types`.
+///
+/// The default `Header` requires a `BlockNumber` type in scope.
+pub fn construct_types(item: &ItemMod, evm: bool) -> syn::Result<TokenStream> {
+    let mut errors = Errors::default();
+    let mut overrides = BTreeMap::new();
+    for item in mod_items(item)? {
+        match item {
+            Item::Type(ty) if TYPE_NAMES.iter().any(|name| ty.ident == name) => {
+                overrides.insert(ty.ident.to_string(), ty.ty.as_ref().clone());
+            }
+            Item::Type(ty) => errors.push(syn::Error::new_spanned(
+                &ty.ident,
+                format!(
+                    "unknown runtime type `{}`, expected one of: {}",
+                    ty.ident,
+                    TYPE_NAMES.join(", ")
+                ),
+            )),
+            item => errors.push(syn::Error::new_spanned(
+                item,
+                "only type aliases are supported in `mod types`",
+            )),
+        }
+    }
+    errors.finish()?;
+
+    // Only `cfg` attributes apply to every alias, doc comments describe the module
+    let cfgs = item.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+    let mut res = quote! {};
+    for name in TYPE_NAMES {
+        let ty = overrides
+            .remove(name)
+            .unwrap_or_else(|| default_type(name, evm));
+        let ident = format_ident!("{name}");
+        let cfgs = cfgs.clone();
+        res.extend(quote! {
+            #(#cfgs)*
+            pub type #ident = #ty;
+        });
+    }
+    Ok(res)
+}
+
+fn default_type(name: &str, evm: bool) -> Type {
+    match name {
+        // The account lookup source, as configured by `SystemConfig::Lookup`
+        "Address" => parse_quote! {
+            <<Runtime as frame_system::Config>::Lookup as sp_runtime::traits::StaticLookup>::Source
+        },
+        "Signature" if evm => parse_quote!(fp_account::EthereumSignature),
+        "Signature" => parse_quote!(sp_runtime::MultiSignature),
+        // `BlockNumber` is defined by the runtime, deriving it from `Runtime` would be cyclic as the block number of
+        // `frame_system` is the one of this header
+        "Header" => parse_quote! {
+            sp_runtime::generic::Header<BlockNumber, sp_runtime::traits::BlakeTwo256>
+        },
+        "Block" => parse_quote!(sp_runtime::generic::Block<Header, UncheckedExtrinsic>),
+        "SignedExtra" => parse_quote! {
+            (
+                frame_system::CheckNonZeroSender<Runtime>,
+                frame_system::CheckSpecVersion<Runtime>,
+                frame_system::CheckTxVersion<Runtime>,
+                frame_system::CheckGenesis<Runtime>,
+                frame_system::CheckEra<Runtime>,
+                frame_system::CheckNonce<Runtime>,
+                frame_system::CheckWeight<Runtime>,
+                pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+                cumulus_primitives_storage_weight_reclaim::StorageWeightReclaim<Runtime>,
+                frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
+            )
+        },
+        // Ethereum transactions are self-contained, they are signed by the Ethereum transaction itself
+        "UncheckedExtrinsic" if evm => parse_quote! {
+            fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>
+        },
+        "UncheckedExtrinsic" => parse_quote! {
+            sp_runtime::generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>
+        },
+        "CheckedExtrinsic" if evm => parse_quote! {
+            fp_self_contained::CheckedExtrinsic<
+                <Runtime as frame_system::Config>::AccountId,
+                RuntimeCall,
+                SignedExtra,
+                sp_core::H160,
+            >
+        },
+        "CheckedExtrinsic" => parse_quote! {
+            sp_runtime::generic::CheckedExtrinsic<
+                <Runtime as frame_system::Config>::AccountId,
+                RuntimeCall,
+                SignedExtra,
+            >
+        },
+        "Migrations" => parse_quote!(()),
+        "Executive" => parse_quote! {
+            frame_executive::Executive<
+                Runtime,
+                Block,
+                frame_system::ChainContext<Runtime>,
+                Runtime,
+                AllPalletsWithSystem,
+                Migrations,
+            >
+        },
+        _ => unreachable!("all names from `TYPE_NAMES` have defaults"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forwards_only_cfg_attributes() {
+        let item: ItemMod = parse_quote! {
+            /// Runtime types
+            #[cfg(feature = "runtime")]
+            mod types {
+                type Migrations = (MyMigration,);
+            }
+        };
+        let types = construct_types(&item, false).unwrap().to_string();
+        assert_eq!(
+            types
+                .matches(&quote!(#[cfg(feature = "runtime")]).to_string())
+                .count(),
+            TYPE_NAMES.len()
+        );
+        assert!(!types.contains("Runtime types"));
+        assert!(types.contains(
+            &quote!(
+                pub type Migrations = (MyMigration,);
+            )
+            .to_string()
+        ));
+    }
+
+    #[test]
+    fn rejects_unknown_types() {
+        let item: ItemMod = parse_quote! {
+            mod types {
+                type BlockNumber = u32;
+            }
+        };
+        assert!(construct_types(&item, false)
+            .unwrap_err()
+            .to_string()
+            .starts_with("unknown runtime type `BlockNumber`"));
+    }
+}
@@ -8,6 +8,8 @@
 //! # Parameters
 //! - `$t`: A type that implements the `AssetsConfig` trait, providing the necessary associated types
 //!   and configurations.
+//! - `exclude(..)` (optional): Names of the pallets, as listed in `PALLET_NAMES`, whose configuration
+//!   is skipped, so that it can be provided by the runtime instead.
 //!
 //! # Important
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
@@ -16,6 +18,9 @@
 #[macro_export]
 macro_rules! impl_openzeppelin_assets {
     ($t:ty) => {
+        $crate::impl_openzeppelin_assets!($t, exclude());
+    };
+    ($t:ty, exclude($($excluded:ident),* $(,)?)) => {
         // Constants for assets configuration
         parameter_types! {
             // The maximum length of a name or symbol stored on-chain.
@@ -42,39 +47,41 @@ macro_rules! impl_openzeppelin_assets {
             }
         }
 
-        impl pallet_assets::Config for Runtime {
-            // The amount of funds that must be reserved when creating a new approval.
-            type ApprovalDeposit = <$t as AssetsConfig>::ApprovalDeposit;
-            // The amount of funds that must be reserved for a non-provider asset account to be maintained.
-            type AssetAccountDeposit = <$t as AssetsConfig>::AssetAccountDeposit;
-            // The basic amount of funds that must be reserved for an asset.
-            type AssetDeposit = <$t as AssetsConfig>::AssetDeposit;
-            // Identifier for the class of asset.
-            type AssetId = <$t as AssetsConfig>::AssetId;
-            // Wrapper around `AssetId` to use in dispatchable call signatures.
-            type AssetIdParameter = parity_scale_codec::Compact<<$t as AssetsConfig>::AssetId>;
-            // The units in which we record balances.
-            type Balance = Balance;
-            #[cfg(feature = "runtime-benchmarks")]
-            type BenchmarkHelper = BenchmarkHelper;
-            type CallbackHandle = ();
-            // Standard asset class creation is only allowed if the origin attempting it and the
-            // asset class are in this set.
-            type CreateOrigin = <$t as AssetsConfig>::CreateOrigin;
-            type Currency = Balances;
-            type Extra = ();
-            // The origin which may forcibly create or destroy an asset or otherwise alter privileged
-	    // attributes.
-            type ForceOrigin = <$t as AssetsConfig>::ForceOrigin;
-            type Freezer = ();
-            type MetadataDepositBase = MetadataDepositBase;
-            type MetadataDepositPerByte = MetadataDepositPerByte;
-            type RemoveItemsLimit = RemoveItemsLimit;
-            // The overarching event type
-            type RuntimeEvent = RuntimeEvent;
-            type StringLimit = StringLimit;
-            type WeightInfo = <$t as AssetsWeight>::Assets;
-        }
+        $crate::__openzeppelin_unless_excluded!(Assets, [$($excluded),*], {
+            impl pallet_assets::Config for Runtime {
+                // The amount of funds that must be reserved when creating a new approval.
+                type ApprovalDeposit = <$t as AssetsConfig>::ApprovalDeposit;
+                // The amount of funds that must be reserved for a non-provider asset account to be maintained.
+                type AssetAccountDeposit = <$t as AssetsConfig>::AssetAccountDeposit;
+                // The basic amount of funds that must be reserved for an asset.
+                type AssetDeposit = <$t as AssetsConfig>::AssetDeposit;
+                // Identifier for the class of asset.
+                type AssetId = <$t as AssetsConfig>::AssetId;
+                // Wrapper around `AssetId` to use in dispatchable call signatures.
+                type AssetIdParameter = parity_scale_codec::Compact<<$t as AssetsConfig>::AssetId>;
+                // The units in which we record balances.
+                type Balance = Balance;
+                #[cfg(feature = "runtime-benchmarks")]
+                type BenchmarkHelper = BenchmarkHelper;
+                type CallbackHandle = ();
+                // Standard asset class creation is only allowed if the origin attempting it and the
+                // asset class are in this set.
+                type CreateOrigin = <$t as AssetsConfig>::CreateOrigin;
+                type Currency = Balances;
+                type Extra = ();
+                // The origin which may forcibly create or destroy an asset or otherwise alter privileged
+    	    // attributes.
+                type ForceOrigin = <$t as AssetsConfig>::ForceOrigin;
+                type Freezer = ();
+                type MetadataDepositBase = MetadataDepositBase;
+                type MetadataDepositPerByte = MetadataDepositPerByte;
+                type RemoveItemsLimit = RemoveItemsLimit;
+                // The overarching event type
+                type RuntimeEvent = RuntimeEvent;
+                type StringLimit = StringLimit;
+                type WeightInfo = <$t as AssetsWeight>::Assets;
+            }
+        });
 
         parameter_types! {
             // Relay Chain `TransactionByteFee` / 10
@@ -82,32 +89,36 @@ macro_rules! impl_openzeppelin_assets {
             pub const OperationalFeeMultiplier: u8 = 5;
         }
 
-        impl pallet_transaction_payment::Config for Runtime {
-            // Fees stay almost constant over the short term and adjust slowly over time.
-            // Spikes in transaction volume in the short term lead to long transaction inclusion times so tipping is allowed
-            // to enable prioritization in proportion to tip amount.
-            type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
-            // Convert a length value into a deductible fee based on the currency type.
-            type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
-            // Handler for withdrawing, refunding and depositing the transaction fee.
-            type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
-            // A fee multiplier for `Operational` extrinsics to compute "virtual tip" to boost their
-	    // `priority`
-            type OperationalFeeMultiplier = OperationalFeeMultiplier;
-            type RuntimeEvent = RuntimeEvent;
-            type WeightToFee = <$t as AssetsConfig>::WeightToFee;
-        }
+        $crate::__openzeppelin_unless_excluded!(TransactionPayment, [$($excluded),*], {
+            impl pallet_transaction_payment::Config for Runtime {
+                // Fees stay almost constant over the short term and adjust slowly over time.
+                // Spikes in transaction volume in the short term lead to long transaction inclusion times so tipping is allowed
+                // to enable prioritization in proportion to tip amount.
+                type FeeMultiplierUpdate = SlowAdjustingFeeUpdate<Self>;
+                // Convert a length value into a deductible fee based on the currency type.
+                type LengthToFee = ConstantMultiplier<Balance, TransactionByteFee>;
+                // Handler for withdrawing, refunding and depositing the transaction fee.
+                type OnChargeTransaction = pallet_transaction_payment::FungibleAdapter<Balances, ()>;
+                // A fee multiplier for `Operational` extrinsics to compute "virtual tip" to boost their
+    	    // `priority`
+                type OperationalFeeMultiplier = OperationalFeeMultiplier;
+                type RuntimeEvent = RuntimeEvent;
+                type WeightToFee = <$t as AssetsConfig>::WeightToFee;
+            }
+        });
 
-        impl pallet_asset_manager::Config for Runtime {
-            type AssetId = AssetId;
-            type AssetRegistrar = <$t as AssetsConfig>::AssetRegistrar;
-            type AssetRegistrarMetadata = <$t as AssetsConfig>::AssetRegistrarMetadata;
-            type Balance = Balance;
-            type ForeignAssetModifierOrigin = <$t as AssetsConfig>::ForeignAssetModifierOrigin;
-            type ForeignAssetType = <$t as AssetsConfig>::AssetType;
-            type RuntimeEvent = RuntimeEvent;
-            type WeightInfo = <$t as AssetsWeight>::AssetManager;
-        }
+        $crate::__openzeppelin_unless_excluded!(AssetManager, [$($excluded),*], {
+            impl pallet_asset_manager::Config for Runtime {
+                type AssetId = AssetId;
+                type AssetRegistrar = <$t as AssetsConfig>::AssetRegistrar;
+                type AssetRegistrarMetadata = <$t as AssetsConfig>::AssetRegistrarMetadata;
+                type Balance = Balance;
+                type ForeignAssetModifierOrigin = <$t as AssetsConfig>::ForeignAssetModifierOrigin;
+                type ForeignAssetType = <$t as AssetsConfig>::AssetType;
+                type RuntimeEvent = RuntimeEvent;
+                type WeightInfo = <$t as AssetsWeight>::AssetManager;
+            }
+        });
     };
 }
 
@@ -116,3 +127,5 @@ pub const PALLET_NAMES: [(&str, &str); 3] = [
     ("TransactionPayment", "pallet_transaction_payment"),
     ("AssetManager", "pallet_asset_manager"),
 ];
+
+pub const BENCHMARKED_PALLETS: [&str; 2] = ["Assets", "AssetManager"];
@@ -8,45 +8,69 @@
 //! # Parameters
 //! - `$t`: A type that implements the `ConsensusConfig` trait, providing the necessary associated types
 //!   and configurations.
+//! - `exclude(..)` (optional): Names of the pallets, as listed in `PALLET_NAMES`, whose configuration
+//!   is skipped, so that it can be provided by the runtime instead.
 //!
 //! # Important
+//! The block production mode is the `RuntimeConsensusMode` generated by `impl_openzeppelin_system!`. `ConsensusHook`
+//! is generated for it and has to be set as `SystemConfig::ConsensusHook`, a different hook is a compile error.
+//!
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
 //! may need to be updated.
 
 #[macro_export]
 macro_rules! impl_openzeppelin_consensus {
     ($t:ty) => {
-        impl pallet_authorship::Config for Runtime {
-            type EventHandler = (CollatorSelection,);
-            type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
-        }
+        $crate::impl_openzeppelin_consensus!($t, exclude());
+    };
+    ($t:ty, exclude($($excluded:ident),* $(,)?)) => {
+        $crate::__openzeppelin_unless_excluded!(Authorship, [$($excluded),*], {
+            impl pallet_authorship::Config for Runtime {
+                type EventHandler = (CollatorSelection,);
+                type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+            }
+        });
 
-        // Allow multiple blocks per slot based on the async backing feature.
-        #[cfg(not(feature = "async-backing"))]
+        // Allow multiple blocks per slot with asynchronous backing.
         parameter_types! {
-            pub const AllowMultipleBlocksPerSlot: bool = false;
+            pub const AllowMultipleBlocksPerSlot: bool =
+                <RuntimeConsensusMode as $crate::ConsensusMode>::ALLOW_MULTIPLE_BLOCKS_PER_SLOT;
         }
 
-        #[cfg(feature = "async-backing")]
-        parameter_types! {
-            pub const AllowMultipleBlocksPerSlot: bool = true;
-        }
+        // The consensus hook of the mode, meant to be used as `SystemConfig::ConsensusHook` and `ConsensusHook`
+        // of the consensus APIs.
+        pub type ConsensusHook = <RuntimeConsensusMode as $crate::ConsensusMode>::ConsensusHook<
+            Runtime,
+            { <$t as ConsensusConfig>::RELAY_CHAIN_SLOT_DURATION_MILLIS },
+            { <$t as ConsensusConfig>::BLOCK_PROCESSING_VELOCITY },
+            { <$t as ConsensusConfig>::UNINCLUDED_SEGMENT_CAPACITY },
+        >;
+
+        // A hook of another mode would build blocks with the velocity and capacity of that mode.
+        const _: () = $crate::check_consensus_hook::<
+            <Runtime as cumulus_pallet_parachain_system::Config>::ConsensusHook,
+            ConsensusHook,
+        >();
 
         // The Aura module extends Aura consensus by managing offline reporting.
-        impl pallet_aura::Config for Runtime {
-            type AllowMultipleBlocksPerSlot = AllowMultipleBlocksPerSlot;
-            // The identifier type for an authority.
-            type AuthorityId = AuraId;
-            // A way to check whether a given validator is disabled and should not be authoring blocks.
-            type DisabledValidators = <$t as ConsensusConfig>::DisabledValidators;
-            // Max number of authorities allowed
-            type MaxAuthorities = <$t as ConsensusConfig>::MaxAuthorities;
-            // A slot duration provider which infers the slot duration from the [pallet_timestamp::Config::MinimumPeriod] by multiplying
-            // it by two, to ensure that authors have the majority of their slot to author within.
-            type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
-        }
+        $crate::__openzeppelin_unless_excluded!(Aura, [$($excluded),*], {
+            impl pallet_aura::Config for Runtime {
+                type AllowMultipleBlocksPerSlot = AllowMultipleBlocksPerSlot;
+                // The identifier type for an authority.
+                type AuthorityId = AuraId;
+                // A way to check whether a given validator is disabled and should not be authoring blocks.
+                type DisabledValidators = <$t as ConsensusConfig>::DisabledValidators;
+                // Max number of authorities allowed
+                type MaxAuthorities = <$t as ConsensusConfig>::MaxAuthorities;
+                // A slot duration provider which infers the slot duration from the [pallet_timestamp::Config::MinimumPeriod] by multiplying
+                // it by two, to ensure that authors have the majority of their slot to author within.
+                type SlotDuration = pallet_aura::MinimumPeriodTimesTwo<Self>;
+            }
+        });
 
-        impl cumulus_pallet_aura_ext::Config for Runtime {}
+        $crate::__openzeppelin_unless_excluded!(AuraExt, [$($excluded),*], {
+            impl cumulus_pallet_aura_ext::Config for Runtime {}
+        });
 
         parameter_types! {
             pub const PotId: PalletId = PalletId(*b"PotStake");
@@ -58,32 +82,34 @@ macro_rules! impl_openzeppelin_consensus {
         }
 
         // A pallet to manage collators in a parachain.
-        impl pallet_collator_selection::Config for Runtime {
-            // The currency mechanism.
-            type Currency = Balances;
-            // should be a multiple of session or things will get inconsistent
-            type KickThreshold = Period;
-            // Maximum number of candidates that we should have without taking into account the invulnerables.
-            type MaxCandidates = <$t as ConsensusConfig>::MaxCandidates;
-            // Maximum number of invulnerables (a set of collators appointed by governance. These accounts will always be collators.)
-            type MaxInvulnerables = <$t as ConsensusConfig>::MaxInvulnerables;
-            // Minimum number eligible collators. Should always be greater than zero.
-            // This ensures that there will always be one collator who can produce a block.
-            type MinEligibleCollators = <$t as ConsensusConfig>::MinEligibleCollators;
-            // Account Identifier from which the internal Pot is generated.
-            type PotId = PotId;
-            // The overarching event type.
-            type RuntimeEvent = RuntimeEvent;
-            // Origin that can dictate updating parameters of this pallet.
-            type UpdateOrigin = <$t as ConsensusConfig>::CollatorSelectionUpdateOrigin;
-            // A stable ID for a validator.
-            type ValidatorId = <Self as frame_system::Config>::AccountId;
-            // A conversion from account ID to validator ID.
-            type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
-            // Validate a user is registered
-            type ValidatorRegistration = Session;
-            type WeightInfo = <$t as ConsensusWeight>::CollatorSelection;
-        }
+        $crate::__openzeppelin_unless_excluded!(CollatorSelection, [$($excluded),*], {
+            impl pallet_collator_selection::Config for Runtime {
+                // The currency mechanism.
+                type Currency = Balances;
+                // should be a multiple of session or things will get inconsistent
+                type KickThreshold = Period;
+                // Maximum number of candidates that we should have without taking into account the invulnerables.
+                type MaxCandidates = <$t as ConsensusConfig>::MaxCandidates;
+                // Maximum number of invulnerables (a set of collators appointed by governance. These accounts will always be collators.)
+                type MaxInvulnerables = <$t as ConsensusConfig>::MaxInvulnerables;
+                // Minimum number eligible collators. Should always be greater than zero.
+                // This ensures that there will always be one collator who can produce a block.
+                type MinEligibleCollators = <$t as ConsensusConfig>::MinEligibleCollators;
+                // Account Identifier from which the internal Pot is generated.
+                type PotId = PotId;
+                // The overarching event type.
+                type RuntimeEvent = RuntimeEvent;
+                // Origin that can dictate updating parameters of this pallet.
+                type UpdateOrigin = <$t as ConsensusConfig>::CollatorSelectionUpdateOrigin;
+                // A stable ID for a validator.
+                type ValidatorId = <Self as frame_system::Config>::AccountId;
+                // A conversion from account ID to validator ID.
+                type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+                // Validate a user is registered
+                type ValidatorRegistration = Session;
+                type WeightInfo = <$t as ConsensusWeight>::CollatorSelection;
+            }
+        });
 
         parameter_types! {
             // pallet_session ends the session after a fixed period of blocks.
@@ -97,28 +123,30 @@ macro_rules! impl_openzeppelin_consensus {
 
         // The Session pallet allows validators to manage their session keys, provides a function for
         // changing the session length, and handles session rotation.
-        impl pallet_session::Config for Runtime {
-            // A session key is actually several keys kept together that provide the various
-            // signing functions required by network authorities/validators in pursuit of their duties.
-            type Keys = SessionKeys;
-            // Something that can predict the next session rotation
-            type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
-            type RuntimeEvent = RuntimeEvent;
-            // Handler when a session has changed
-            type SessionHandler =
-                <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
-            // Handler for managing new session.
-            type SessionManager = CollatorSelection;
-            // Indicator for when to end the session.
-            type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
-            // Every account has an associated validator ID. For some simple staking
-            // systems, this may just be the same as the account ID. For staking systems using a
-            // stash/controller model, the validator ID would be the stash account ID of the controller.
-            type ValidatorId = <Self as frame_system::Config>::AccountId;
-            // we don't have stash and controller, thus we don't need the convert as well.
-            type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
-            type WeightInfo = <$t as ConsensusWeight>::Session;
-        }
+        $crate::__openzeppelin_unless_excluded!(Session, [$($excluded),*], {
+            impl pallet_session::Config for Runtime {
+                // A session key is actually several keys kept together that provide the various
+                // signing functions required by network authorities/validators in pursuit of their duties.
+                type Keys = SessionKeys;
+                // Something that can predict the next session rotation
+                type NextSessionRotation = pallet_session::PeriodicSessions<Period, Offset>;
+                type RuntimeEvent = RuntimeEvent;
+                // Handler when a session has changed
+                type SessionHandler =
+                    <SessionKeys as sp_runtime::traits::OpaqueKeys>::KeyTypeIdProviders;
+                // Handler for managing new session.
+                type SessionManager = CollatorSelection;
+                // Indicator for when to end the session.
+                type ShouldEndSession = pallet_session::PeriodicSessions<Period, Offset>;
+                // Every account has an associated validator ID. For some simple staking
+                // systems, this may just be the same as the account ID. For staking systems using a
+                // stash/controller model, the validator ID would be the stash account ID of the controller.
+                type ValidatorId = <Self as frame_system::Config>::AccountId;
+                // we don't have stash and controller, thus we don't need the convert as well.
+                type ValidatorIdOf = pallet_collator_selection::IdentityCollator;
+                type WeightInfo = <$t as ConsensusWeight>::Session;
+            }
+        });
     };
 }
 
@@ -129,3 +157,5 @@ pub const PALLET_NAMES: [(&str, &str); 5] = [
     ("CollatorSelection", "pallet_collator_selection"),
     ("Session", "pallet_session"),
 ];
+
+pub const BENCHMARKED_PALLETS: [&str; 2] = ["CollatorSelection", "Session"];
@@ -10,6 +10,8 @@
 //! # Parameters
 //! - `$t`: A type that implements the `EvmConfig` trait, providing the necessary associated types
 //!   and configurations.
+//! - `exclude(..)` (optional): Names of the pallets, as listed in `PALLET_NAMES`, whose configuration
+//!   is skipped, so that it can be provided by the runtime instead.
 //!
 //! # Important
 //! Rerun benchmarks if making changes to runtime configuration, as weight calculations
@@ -18,16 +20,21 @@
 #[macro_export]
 macro_rules! impl_openzeppelin_evm {
     ($t:ty) => {
+        $crate::impl_openzeppelin_evm!($t, exclude());
+    };
+    ($t:ty, exclude($($excluded:ident),* $(,)?)) => {
         parameter_types! {
             pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
         }
 
-        impl pallet_ethereum::Config for Runtime {
-            type ExtraDataLength = ConstU32<30>;
-            type PostLogContent = PostBlockAndTxnHashes;
-            type RuntimeEvent = RuntimeEvent;
-            type StateRoot = pallet_ethereum::IntermediateStateRoot<Self>;
-        }
+        $crate::__openzeppelin_unless_excluded!(Ethereum, [$($excluded),*], {
+            impl pallet_ethereum::Config for Runtime {
+                type ExtraDataLength = ConstU32<30>;
+                type PostLogContent = PostBlockAndTxnHashes;
+                type RuntimeEvent = RuntimeEvent;
+                type StateRoot = pallet_ethereum::IntermediateStateRoot<Self>;
+            }
+        });
 
         parameter_types! {
             // Block gas limit is calculated with target for 75% of block capacity and ratio of maximum block weight and weight per gas
@@ -38,48 +45,52 @@ macro_rules! impl_openzeppelin_evm {
             pub SuicideQuickClearLimit: u32 = 0;
         }
 
-        impl pallet_evm::Config for Runtime {
-            // Mapping from address to account id.
-            type AddressMapping = <$t as EvmConfig>::AddressMapping;
-            // The block gas limit. Can be a simple constant, or an adjustment algorithm in another pallet.
-            type BlockGasLimit = BlockGasLimit;
-            // Block number to block hash.
-            type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
-            // Allow the origin to call on behalf of given address.
-            type CallOrigin = <$t as EvmConfig>::CallOrigin;
-            // Chain ID of EVM
-            type ChainId = EVMChainId;
-            type Currency = Balances;
-            // Calculator for current gas price.
-            type FeeCalculator = BaseFee;
-            // Find author for the current block.
-            type FindAuthor = <$t as EvmConfig>::FindAuthor;
-            // Gas limit PoV size ratio.
-            type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
-            // Maps Ethereum gas to Substrate weight.
-            type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
-            // To handle fee deduction for EVM transactions.
-            type OnChargeTransaction = EVMCurrencyAdapter<Balances, ()>;
-            // Called on create calls, used to record owner
-            type OnCreate = ();
-            // Precompiles associated with this EVM engine.
-            type PrecompilesType = <$t as EvmConfig>::PrecompilesType;
-            type PrecompilesValue = <$t as EvmConfig>::PrecompilesValue;
-            // EVM execution runner.
-            type Runner = pallet_evm::runner::stack::Runner<Self>;
-            // The overarching event type.
-            type RuntimeEvent = RuntimeEvent;
-            type SuicideQuickClearLimit = SuicideQuickClearLimit;
-            // Get the timestamp for the current block.
-            type Timestamp = Timestamp;
-            type WeightInfo = <$t as EvmWeight>::Evm;
-            // Weight corresponding to a gas unit.
-            type WeightPerGas = WeightPerGas;
-            // Allow the origin to withdraw on behalf of given address.
-            type WithdrawOrigin = <$t as EvmConfig>::WithdrawOrigin;
-        }
+        $crate::__openzeppelin_unless_excluded!(EVM, [$($excluded),*], {
+            impl pallet_evm::Config for Runtime {
+                // Mapping from address to account id.
+                type AddressMapping = <$t as EvmConfig>::AddressMapping;
+                // The block gas limit. Can be a simple constant, or an adjustment algorithm in another pallet.
+                type BlockGasLimit = BlockGasLimit;
+                // Block number to block hash.
+                type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
+                // Allow the origin to call on behalf of given address.
+                type CallOrigin = <$t as EvmConfig>::CallOrigin;
+                // Chain ID of EVM
+                type ChainId = EVMChainId;
+                type Currency = Balances;
+                // Calculator for current gas price.
+                type FeeCalculator = BaseFee;
+                // Find author for the current block.
+                type FindAuthor = <$t as EvmConfig>::FindAuthor;
+                // Gas limit PoV size ratio.
+                type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+                // Maps Ethereum gas to Substrate weight.
+                type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+                // To handle fee deduction for EVM transactions.
+                type OnChargeTransaction = EVMCurrencyAdapter<Balances, ()>;
+                // Called on create calls, used to record owner
+                type OnCreate = ();
+                // Precompiles associated with this EVM engine.
+                type PrecompilesType = <$t as EvmConfig>::PrecompilesType;
+                type PrecompilesValue = <$t as EvmConfig>::PrecompilesValue;
+                // EVM execution runner.
+                type Runner = pallet_evm::runner::stack::Runner<Self>;
+                // The overarching event type.
+                type RuntimeEvent = RuntimeEvent;
+                type SuicideQuickClearLimit = SuicideQuickClearLimit;
+                // Get the timestamp for the current block.
+                type Timestamp = Timestamp;
+                type WeightInfo = <$t as EvmWeight>::Evm;
+                // Weight corresponding to a gas unit.
+                type WeightPerGas = WeightPerGas;
+                // Allow the origin to withdraw on behalf of given address.
+                type WithdrawOrigin = <$t as EvmConfig>::WithdrawOrigin;
+            }
+        });
 
-        impl pallet_evm_chain_id::Config for Runtime {}
+        $crate::__openzeppelin_unless_excluded!(EVMChainId, [$($excluded),*], {
+            impl pallet_evm_chain_id::Config for Runtime {}
+        });
 
         parameter_types! {
             // Starting value for base fee. Set at the same value as in Ethereum.
@@ -103,12 +114,14 @@ macro_rules! impl_openzeppelin_evm {
                 Permill::from_parts(1_000_000)
             }
         }
-        impl pallet_base_fee::Config for Runtime {
-            type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
-            type DefaultElasticity = DefaultElasticity;
-            type RuntimeEvent = RuntimeEvent;
-            type Threshold = BaseFeeThreshold;
-        }
+        $crate::__openzeppelin_unless_excluded!(BaseFee, [$($excluded),*], {
+            impl pallet_base_fee::Config for Runtime {
+                type DefaultBaseFeePerGas = DefaultBaseFeePerGas;
+                type DefaultElasticity = DefaultElasticity;
+                type RuntimeEvent = RuntimeEvent;
+                type Threshold = BaseFeeThreshold;
+            }
+        });
 
         parameter_types! {
             // This is the relative view of erc20 assets.
@@ -122,12 +135,14 @@ macro_rules! impl_openzeppelin_evm {
             };
         }
 
-        impl pallet_erc20_xcm_bridge::Config for Runtime {
-            type AccountIdConverter = <$t as EvmConfig>::LocationToH160;
-            type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
-            type Erc20TransferGasLimit = <$t as EvmConfig>::Erc20XcmBridgeTransferGasLimit;
-            type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
-        }
+        $crate::__openzeppelin_unless_excluded!(Erc20XcmBridge, [$($excluded),*], {
+            impl pallet_erc20_xcm_bridge::Config for Runtime {
+                type AccountIdConverter = <$t as EvmConfig>::LocationToH160;
+                type Erc20MultilocationPrefix = Erc20XcmBridgePalletLocation;
+                type Erc20TransferGasLimit = <$t as EvmConfig>::Erc20XcmBridgeTransferGasLimit;
+                type EvmRunner = pallet_evm::runner::stack::Runner<Self>;
+            }
+        });
     };
 }
 
@@ -138,3 +153,5 @@ pub const PALLET_NAMES: [(&str, &str); 5] = [
     ("EVMChainId", "pallet_evm_chain_id"),
     ("Erc20XcmBridge", "pallet_erc20_xcm_bridge"),
 ];
+
+pub const BENCHMARKED_PALLETS: [&str; 1] = ["EVM"];
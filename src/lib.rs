@@ -5,6 +5,7 @@ pub mod assets;
 pub mod consensus;
 pub mod evm;
 pub mod governance;
+pub mod runtime_api;
 pub mod system;
 pub mod tanssi;
 pub mod weights;
@@ -22,6 +23,9 @@ pub trait SystemConfig: SystemWeight {
     type ScheduleOrigin;
     type PreimageOrigin;
     type ProxyType;
+    /// How parachain blocks are backed: `SyncBacking`, `AsyncBacking` or `ElasticScaling`
+    type ConsensusMode: ConsensusMode = SyncBacking;
+    /// Consensus hook of the parachain system, the `ConsensusHook` of the mode with the Consensus abstraction
     type ConsensusHook;
     type SlotDuration;
     type OnTimestampSet: OnTimestampSet<u64>;
@@ -41,8 +45,125 @@ pub trait ConsensusConfig: ConsensusWeight {
     type MaxInvulnerables = ConstU32<20>;
     type MinEligibleCollators = ConstU32<4>;
     type CollatorSelectionUpdateOrigin;
+    /// Relay chain slot duration in milliseconds, used by the consensus hook of asynchronous backing
+    const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32 = 6000;
+    /// Parachain blocks built per relay chain block, one per core assigned to the parachain
+    const BLOCK_PROCESSING_VELOCITY: u32 = 1;
+    /// Parachain blocks that may be built on top of the last included block
+    const UNINCLUDED_SEGMENT_CAPACITY: u32 = 3;
 }
 
+/// Block production mode of the parachain, selected by `SystemConfig::ConsensusMode`.
+pub trait ConsensusMode {
+    /// Whether a collator may author several blocks in one Aura slot
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool;
+    /// Check of the relay parent number of a new parachain block against the previous one
+    type CheckAssociatedRelayNumber: cumulus_pallet_parachain_system::CheckAssociatedRelayNumber;
+    /// Consensus hook of the parachain system, given the relay chain slot duration, the velocity and
+    /// the unincluded segment capacity
+    type ConsensusHook<
+        Runtime,
+        const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+        const VELOCITY: u32,
+        const CAPACITY: u32,
+    >;
+    /// Selector of the core the next parachain block is built for
+    type SelectCore<Runtime: frame_system::Config>: cumulus_pallet_parachain_system::SelectCore;
+}
+
+/// Synchronous backing, a parachain block is built on top of the block included in the relay parent.
+pub struct SyncBacking;
+
+impl ConsensusMode for SyncBacking {
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = false;
+    type CheckAssociatedRelayNumber = cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
+    type ConsensusHook<
+        Runtime,
+        const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+        const VELOCITY: u32,
+        const CAPACITY: u32,
+    > = cumulus_pallet_parachain_system::ExpectParentIncluded;
+    type SelectCore<Runtime: frame_system::Config> =
+        cumulus_pallet_parachain_system::DefaultCoreSelector<Runtime>;
+}
+
+/// Asynchronous backing, parachain blocks are built on top of unincluded blocks.
+pub struct AsyncBacking;
+
+impl ConsensusMode for AsyncBacking {
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = true;
+    type CheckAssociatedRelayNumber =
+        cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
+    type ConsensusHook<
+        Runtime,
+        const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+        const VELOCITY: u32,
+        const CAPACITY: u32,
+    > = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+        Runtime,
+        RELAY_CHAIN_SLOT_DURATION_MILLIS,
+        VELOCITY,
+        CAPACITY,
+    >;
+    type SelectCore<Runtime: frame_system::Config> =
+        cumulus_pallet_parachain_system::DefaultCoreSelector<Runtime>;
+}
+
+/// Asynchronous backing on several cores, `BLOCK_PROCESSING_VELOCITY` blocks are built per relay chain block.
+pub struct ElasticScaling;
+
+impl ConsensusMode for ElasticScaling {
+    const ALLOW_MULTIPLE_BLOCKS_PER_SLOT: bool = true;
+    type CheckAssociatedRelayNumber =
+        cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
+    type ConsensusHook<
+        Runtime,
+        const RELAY_CHAIN_SLOT_DURATION_MILLIS: u32,
+        const VELOCITY: u32,
+        const CAPACITY: u32,
+    > = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
+        Runtime,
+        RELAY_CHAIN_SLOT_DURATION_MILLIS,
+        VELOCITY,
+        CAPACITY,
+    >;
+    type SelectCore<Runtime: frame_system::Config> =
+        cumulus_pallet_parachain_system::LookaheadCoreSelector<Runtime>;
+}
+
+/// Consensus mode of the runtime, implemented for `Runtime` by `impl_openzeppelin_system!`.
+pub trait ConfiguredConsensusMode {
+    type ConsensusMode: ConsensusMode;
+}
+
+/// Implemented only for the same consensus mode, the consensus APIs check with it that they are generated for the
+/// mode of `SystemConfig`.
+#[diagnostic::on_unimplemented(
+    message = "`SystemConfig::ConsensusMode` is `{Self}`, but the consensus APIs are implemented for `{Declared}`",
+    label = "configured consensus mode",
+    note = "declare `type ConsensusMode = {Self};` in `mod consensus` of `mod apis`"
+)]
+pub trait SameConsensusMode<Declared> {}
+
+impl<Mode: ConsensusMode> SameConsensusMode<Mode> for Mode {}
+
+/// Compiles only if `Configured` and `Declared` are the same consensus mode.
+pub const fn check_consensus_mode<Configured: SameConsensusMode<Declared>, Declared>() {}
+
+/// Implemented only for the same consensus hook, `impl_openzeppelin_consensus!` checks with it that the parachain
+/// system runs the hook of the consensus mode.
+#[diagnostic::on_unimplemented(
+    message = "the parachain system runs `{Self}`, but the consensus mode requires `{Generated}`",
+    label = "configured consensus hook",
+    note = "set `type ConsensusHook = ConsensusHook;` in `SystemConfig`"
+)]
+pub trait SameConsensusHook<Generated> {}
+
+impl<Hook> SameConsensusHook<Hook> for Hook {}
+
+/// Compiles only if `Configured` and `Generated` are the same consensus hook.
+pub const fn check_consensus_hook<Configured: SameConsensusHook<Generated>, Generated>() {}
+
 pub trait AssetsConfig: AssetsWeight {
     type ApprovalDeposit;
     type AssetAccountDeposit;
@@ -149,3 +270,22 @@ pub trait TanssiConfig: TanssiWeight {
 fn example() {
     assert_eq!(1 + 1, 2);
 }
+
+/// Expands `$items` unless `$pallet` is one of the excluded pallets.
+///
+/// Used by the `impl_openzeppelin_*` macros to skip the configuration of pallets excluded from the
+/// abstraction.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __openzeppelin_unless_excluded {
+    ($pallet:ident, [], { $($items:tt)* }) => { $($items)* };
+    ($pallet:ident, [$excluded:ident $(, $rest:ident)*], { $($items:tt)* }) => {
+        macro_rules! __openzeppelin_excluded {
+            ($pallet) => {};
+            ($excluded) => {
+                $crate::__openzeppelin_unless_excluded!($pallet, [$($rest),*], { $($items)* });
+            };
+        }
+        __openzeppelin_excluded!($excluded);
+    };
+}
@@ -0,0 +1,73 @@
+//! Runtime APIs declared by the OpenZeppelin abstractions.
+//!
+//! `openzeppelin_construct_runtime` generates the `RUNTIME_COMPOSITION` constant describing the
+//! abstractions of the runtime, and the `composition` module of `openzeppelin_runtime_apis`
+//! implements `OpenZeppelinRuntimeApi` returning it.
+//! The `assets` module of `openzeppelin_runtime_apis` implements `ForeignAssetsApi` with
+//! `pallet_asset_manager`.
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::{borrow::Cow, vec::Vec};
+
+/// Version of the abstractions, and of the defaults they provide, the runtime was built with.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Abstraction the runtime is composed of.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct AbstractionInfo {
+    /// Name of the abstraction, e.g. `Governance`.
+    pub name: Cow<'static, str>,
+    /// Version of the abstractions crate.
+    pub version: Cow<'static, str>,
+    /// Pallets of the abstraction included in the runtime, excluded pallets are omitted.
+    pub pallets: Cow<'static, [PalletComposition]>,
+}
+
+impl AbstractionInfo {
+    pub const fn new(name: &'static str, pallets: &'static [PalletComposition]) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            version: Cow::Borrowed(VERSION),
+            pallets: Cow::Borrowed(pallets),
+        }
+    }
+}
+
+/// Pallet of an abstraction and its index in the runtime.
+#[derive(Clone, PartialEq, Eq, Debug, Encode, Decode, TypeInfo)]
+pub struct PalletComposition {
+    /// Name of the pallet in the runtime, e.g. `Sudo`.
+    pub name: Cow<'static, str>,
+    /// Index of the pallet in the runtime.
+    pub index: u8,
+}
+
+impl PalletComposition {
+    pub const fn new(name: &'static str, index: u8) -> Self {
+        Self {
+            name: Cow::Borrowed(name),
+            index,
+        }
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// API to detect the OpenZeppelin abstractions the runtime is composed of.
+    pub trait OpenZeppelinRuntimeApi {
+        /// Returns the abstractions of the runtime along with their pallets.
+        fn runtime_composition() -> Vec<AbstractionInfo>;
+    }
+
+    /// API to resolve the foreign assets registered in `pallet_asset_manager`.
+    pub trait ForeignAssetsApi<AssetType, AssetId>
+    where
+        AssetType: Codec,
+        AssetId: Codec,
+    {
+        /// Returns the local asset id of the foreign asset type, if it is registered.
+        fn asset_id(asset_type: AssetType) -> Option<AssetId>;
+        /// Returns the foreign asset type of the local asset id, if it is registered.
+        fn asset_type(asset_id: AssetId) -> Option<AssetType>;
+    }
+}